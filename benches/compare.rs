@@ -0,0 +1,26 @@
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use package_family_name::PackageFamilyName;
+
+fn compare(c: &mut Criterion) {
+    let name = "MicrosoftCorporationII.WindowsSubsystemForLinux";
+    let filter = PackageFamilyName::new(name, "CN=Contoso");
+
+    // Same long package name, different publishers
+    let family_names = (0..1000)
+        .map(|index| PackageFamilyName::new(name, format!("CN=Contoso {index}")))
+        .collect::<Vec<_>>();
+
+    c.bench_function("PackageFamilyName::eq (1000 mostly unequal)", |b| {
+        b.iter(|| {
+            family_names
+                .iter()
+                .filter(|family_name| **family_name == *black_box(&filter))
+                .count()
+        })
+    });
+}
+
+criterion_group!(benches, compare);
+criterion_main!(benches);
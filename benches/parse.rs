@@ -0,0 +1,41 @@
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use package_family_name::{PackageFamilyName, PublisherId};
+
+fn parse(c: &mut Criterion) {
+    c.bench_function("PublisherId::from_str", |b| {
+        b.iter(|| black_box("8wekyb3d8bbwe").parse::<PublisherId>())
+    });
+
+    c.bench_function("PackageFamilyName::from_str", |b| {
+        b.iter(|| black_box("Microsoft.WindowsTerminal_8wekyb3d8bbwe").parse::<PackageFamilyName>())
+    });
+
+    let family_names = (0..1000)
+        .map(|index| format!("Contoso.App{index}_8wekyb3d8bbwe"))
+        .collect::<Vec<_>>();
+
+    c.bench_function("PackageFamilyName::from_str (1000 names)", |b| {
+        b.iter(|| {
+            family_names
+                .iter()
+                .map(|family_name| family_name.parse::<PackageFamilyName>())
+                .filter(Result::is_ok)
+                .count()
+        })
+    });
+
+    c.bench_function("PackageFamilyName::parse (1000 names)", |b| {
+        b.iter(|| {
+            family_names
+                .iter()
+                .map(|family_name| PackageFamilyName::parse(family_name))
+                .filter(Result::is_ok)
+                .count()
+        })
+    });
+}
+
+criterion_group!(benches, parse);
+criterion_main!(benches);
@@ -0,0 +1,54 @@
+/*
+ * C bindings for the package-family-name crate, mirroring the Windows
+ * PackageFamilyNameFromId and PackageNameAndPublisherIdFromFamilyName functions.
+ *
+ * Strings are NUL-terminated and UTF-8 encoded. Every function returns PFN_OK on success,
+ * PFN_INVALID_ARGUMENT if a pointer is null or an input is invalid, and
+ * PFN_INSUFFICIENT_BUFFER if an output buffer is too small, in which case nothing is written.
+ */
+
+#ifndef PACKAGE_FAMILY_NAME_H
+#define PACKAGE_FAMILY_NAME_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* The function succeeded. */
+#define PFN_OK 0
+
+/* A pointer is null, a string isn't valid UTF-8, or an input isn't a valid package name or
+ * family name. */
+#define PFN_INVALID_ARGUMENT (-1)
+
+/* An output buffer is too small for the string and its NUL terminator. */
+#define PFN_INSUFFICIENT_BUFFER (-2)
+
+/* The size of a buffer that fits any family name, including its NUL terminator. */
+#define PFN_MAX_LENGTH 65
+
+/* The size of a buffer that fits any Publisher Id, including its NUL terminator. */
+#define PFN_PUBLISHER_ID_LENGTH 14
+
+/*
+ * Calculates the Package Family Name from an identity name and an identity publisher, writing
+ * it to `out`, which is valid for writes of `out_len` bytes.
+ */
+int32_t pfn_from_name_and_publisher(const char *name, const char *publisher, char *out,
+                                    size_t out_len);
+
+/*
+ * Splits a Package Family Name into its package name and its canonical lowercase Publisher Id,
+ * writing them to `name_out` and `publisher_id_out`. On failure neither buffer is modified.
+ */
+int32_t pfn_name_and_publisher_id(const char *family_name, char *name_out, size_t name_out_len,
+                                  char *publisher_id_out, size_t publisher_id_out_len);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* PACKAGE_FAMILY_NAME_H */
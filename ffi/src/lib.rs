@@ -0,0 +1,39 @@
+//! Builds the `extern "C"` functions in `package_family_name::ffi` as a shared and a static
+//! library, declared in `include/package_family_name.h`.
+//!
+//! The main crate is `no_std`, so it can't be a `cdylib` or `staticlib` itself; this crate links
+//! it with `std` to provide the panic handler and allocator those need.
+
+pub use package_family_name::ffi::*;
+
+#[cfg(test)]
+mod tests {
+    use package_family_name::ffi::{
+        PFN_INSUFFICIENT_BUFFER, PFN_INVALID_ARGUMENT, PFN_MAX_LENGTH, PFN_OK,
+    };
+    use package_family_name::PublisherId;
+
+    const HEADER: &str = include_str!("../include/package_family_name.h");
+
+    #[test]
+    fn test_header_matches_constants() {
+        for (name, value) in [
+            ("PFN_OK", PFN_OK.to_string()),
+            ("PFN_INVALID_ARGUMENT", format!("({PFN_INVALID_ARGUMENT})")),
+            (
+                "PFN_INSUFFICIENT_BUFFER",
+                format!("({PFN_INSUFFICIENT_BUFFER})"),
+            ),
+            ("PFN_MAX_LENGTH", PFN_MAX_LENGTH.to_string()),
+            (
+                "PFN_PUBLISHER_ID_LENGTH",
+                (PublisherId::LENGTH + 1).to_string(),
+            ),
+        ] {
+            assert!(
+                HEADER.contains(&format!("#define {name} {value}\n")),
+                "{name} should be defined as {value}"
+            );
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use alloc::string::String;
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::{
+    validate_package_name, PackageFamilyName, PublisherId, MAX_PACKAGE_NAME_LEN,
+    MIN_PACKAGE_NAME_LEN,
+};
+
+/// The characters allowed in a package name. Periods and dashes are last so that the first and
+/// last characters can be chosen from the alphanumeric characters only.
+const NAME_CHARS: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789.-";
+
+const ALPHANUMERIC: &[u8] = NAME_CHARS.split_at(62).0;
+
+/// Generates 8 hash bytes, so that every generated Publisher Id is valid.
+impl<'a> Arbitrary<'a> for PublisherId {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        u.arbitrary().map(Self::from_hash_bytes)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <[u8; 8]>::size_hint(depth)
+    }
+}
+
+/// Generates a valid package name and an arbitrary Publisher Id.
+impl<'a> Arbitrary<'a> for PackageFamilyName<'_> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let len = u.int_in_range(MIN_PACKAGE_NAME_LEN..=MAX_PACKAGE_NAME_LEN)?;
+
+        let mut package_name = String::with_capacity(len);
+        for index in 0..len {
+            let chars = if index == 0 || index == len - 1 {
+                ALPHANUMERIC
+            } else {
+                NAME_CHARS
+            };
+            package_name.push(char::from(*u.choose(chars)?));
+        }
+
+        // Reserved names such as CON or COM1 all start with one of these letters
+        if validate_package_name(&package_name).is_err() {
+            package_name.replace_range(..1, "X");
+        }
+
+        Ok(PackageFamilyName::from_owned_parts(
+            package_name,
+            u.arbitrary()?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+    use alloc::vec::Vec;
+
+    use arbitrary::{Arbitrary, Unstructured};
+
+    use crate::{validate_package_name, PackageFamilyName};
+
+    #[test]
+    fn test_arbitrary_is_valid() {
+        let data = (0..=u8::MAX).cycle().take(4096).collect::<Vec<_>>();
+        let mut u = Unstructured::new(&data);
+
+        while !u.is_empty() {
+            let Ok(package_family_name) = PackageFamilyName::arbitrary(&mut u) else {
+                break;
+            };
+
+            assert_eq!(
+                validate_package_name(package_family_name.package_name()),
+                Ok(())
+            );
+            assert_eq!(
+                package_family_name.to_string().parse(),
+                Ok(package_family_name)
+            );
+        }
+    }
+}
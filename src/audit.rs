@@ -0,0 +1,147 @@
+use alloc::vec::Vec;
+
+use crate::package_family_name::eq_ignore_case;
+use crate::PackageFamilyName;
+
+/// The Publisher Id of `CN=Microsoft Corporation, O=Microsoft Corporation, L=Redmond,
+/// S=Washington, C=US`.
+const MICROSOFT_PUBLISHER_ID: &str = "8wekyb3d8bbwe";
+
+/// A heuristic advisory produced by [`audit`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AuditWarning<'a> {
+    /// The package name matches a known package name except for its casing.
+    CasingDifference { known: &'a PackageFamilyName<'a> },
+    /// The package name looks like a known package name once visually confusable characters
+    /// (such as `0` and `o`, `rn` and `m`, or Cyrillic `а` and Latin `a`) are folded together.
+    Homoglyph { known: &'a PackageFamilyName<'a> },
+    /// The package name matches a known package name but the Publisher Id is different.
+    PublisherMismatch { known: &'a PackageFamilyName<'a> },
+    /// The Publisher Id is Microsoft's but the package name doesn't start with `Microsoft`.
+    UnexpectedMicrosoftName,
+}
+
+/// Checks a family name against a list of known family names for signs of typosquatting or
+/// impersonation.
+///
+/// These are heuristics: a warning is a prompt for a human to take a closer look, not proof that
+/// something is wrong. A family name that is exactly equal to a known one produces no warnings.
+pub fn audit<'a>(
+    family_name: &PackageFamilyName<'_>,
+    known: &'a [PackageFamilyName<'a>],
+) -> Vec<AuditWarning<'a>> {
+    let mut warnings = Vec::new();
+    let name = family_name.package_name();
+
+    for known in known {
+        let known_name = known.package_name();
+
+        if eq_ignore_case(name, known_name) {
+            if name != known_name {
+                warnings.push(AuditWarning::CasingDifference { known });
+            }
+            if family_name.publisher_id() != known.publisher_id() {
+                warnings.push(AuditWarning::PublisherMismatch { known });
+            }
+        } else if skeleton(name).eq(skeleton(known_name)) {
+            warnings.push(AuditWarning::Homoglyph { known });
+        }
+    }
+
+    if family_name.publisher_id().as_str() == MICROSOFT_PUBLISHER_ID
+        && !name
+            .get(.."Microsoft".len())
+            .is_some_and(|prefix| eq_ignore_case(prefix, "Microsoft"))
+    {
+        warnings.push(AuditWarning::UnexpectedMicrosoftName);
+    }
+
+    warnings
+}
+
+/// Folds a name into a lowercase form where visually confusable characters are the same.
+fn skeleton(name: &str) -> impl Iterator<Item = char> + '_ {
+    let mut chars = name.chars().peekable();
+
+    core::iter::from_fn(move || {
+        let character = chars.next()?;
+        Some(match character.to_ascii_lowercase() {
+            'r' if chars
+                .next_if(|&next| next.eq_ignore_ascii_case(&'n'))
+                .is_some() =>
+            {
+                'm'
+            }
+            '0' | 'о' | 'О' | 'ο' | 'Ο' => 'o',
+            '1' | 'i' | '|' | 'і' | 'І' | 'ι' => 'l',
+            '5' => 's',
+            'а' | 'А' | 'α' => 'a',
+            'е' | 'Е' | 'ε' => 'e',
+            'р' | 'Р' | 'ρ' => 'p',
+            'с' | 'С' => 'c',
+            'х' | 'Х' | 'χ' => 'x',
+            'у' | 'У' => 'y',
+            lowercase => lowercase,
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use crate::{audit, AuditWarning, PackageFamilyName};
+
+    const MICROSOFT: &str =
+        "CN=Microsoft Corporation, O=Microsoft Corporation, L=Redmond, S=Washington, C=US";
+
+    #[test]
+    fn test_audit_homoglyph() {
+        let known = [PackageFamilyName::new("Microsoft.PowerShell", MICROSOFT)];
+        let family_name = PackageFamilyName::new("Micr0soft.PowerShe1l", "CN=Contoso");
+
+        assert_eq!(
+            audit(&family_name, &known),
+            vec![AuditWarning::Homoglyph { known: &known[0] }]
+        );
+    }
+
+    #[test]
+    fn test_audit_casing_difference() {
+        let known = [PackageFamilyName::new("Microsoft.PowerShell", MICROSOFT)];
+
+        assert_eq!(
+            audit(
+                &PackageFamilyName::new("microsoft.powershell", MICROSOFT),
+                &known
+            ),
+            vec![AuditWarning::CasingDifference { known: &known[0] }]
+        );
+        assert_eq!(
+            audit(
+                &PackageFamilyName::new("Microsoft.Powershell", "CN=Contoso"),
+                &known
+            ),
+            vec![
+                AuditWarning::CasingDifference { known: &known[0] },
+                AuditWarning::PublisherMismatch { known: &known[0] }
+            ]
+        );
+        assert!(audit(&known[0], &known).is_empty());
+
+        // Non-ASCII names are compared the same way as family names are
+        let known = [PackageFamilyName::new("Zürich.App", "CN=Contoso")];
+        assert_eq!(
+            audit(&PackageFamilyName::new("ZÜRICH.App", "CN=Contoso"), &known),
+            vec![AuditWarning::CasingDifference { known: &known[0] }]
+        );
+    }
+
+    #[test]
+    fn test_audit_unexpected_microsoft_name() {
+        assert_eq!(
+            audit(&PackageFamilyName::new("Contoso.App", MICROSOFT), &[]),
+            vec![AuditWarning::UnexpectedMicrosoftName]
+        );
+    }
+}
@@ -0,0 +1,70 @@
+use alloc::string::{String, ToString};
+
+use borsh::io::{Error, ErrorKind, Read, Write};
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::{PackageFamilyName, PackageFamilyNameError, PublisherId};
+
+/// Serializes as the 8 bytes of the hash that the Publisher Id encodes, which round-trip to an
+/// equal Publisher Id as [`hash_bytes`](PublisherId::hash_bytes) describes.
+impl BorshSerialize for PublisherId {
+    fn serialize<W: Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        self.hash_bytes().serialize(writer)
+    }
+}
+
+impl BorshDeserialize for PublisherId {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> borsh::io::Result<Self> {
+        <[u8; 8]>::deserialize_reader(reader).map(Self::from_hash_bytes)
+    }
+}
+
+/// Serializes as the family name string.
+impl BorshSerialize for PackageFamilyName<'_> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        self.to_string().serialize(writer)
+    }
+}
+
+/// Deserializes from the family name string, validating it the same as [`FromStr`](core::str::FromStr).
+impl BorshDeserialize for PackageFamilyName<'_> {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> borsh::io::Result<Self> {
+        String::deserialize_reader(reader)?
+            .parse()
+            .map_err(|error: PackageFamilyNameError| {
+                Error::new(ErrorKind::InvalidData, error.to_string())
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{PackageFamilyName, PublisherId};
+
+    #[test]
+    fn test_publisher_id_borsh() {
+        let publisher_id = "8wekyb3d8bbwe".parse::<PublisherId>().unwrap();
+
+        let bytes = borsh::to_vec(&publisher_id).unwrap();
+        assert_eq!(bytes, publisher_id.hash_bytes());
+        assert_eq!(
+            borsh::from_slice::<PublisherId>(&bytes).unwrap(),
+            publisher_id
+        );
+    }
+
+    #[test]
+    fn test_package_family_name_borsh() {
+        let package_family_name: PackageFamilyName =
+            "Microsoft.WindowsTerminal_8wekyb3d8bbwe".parse().unwrap();
+
+        let bytes = borsh::to_vec(&package_family_name).unwrap();
+        assert_eq!(
+            borsh::from_slice::<PackageFamilyName>(&bytes).unwrap(),
+            package_family_name
+        );
+
+        let invalid = borsh::to_vec("Microsoft.WindowsTerminal").unwrap();
+        assert!(borsh::from_slice::<PackageFamilyName>(&invalid).is_err());
+    }
+}
@@ -0,0 +1,313 @@
+//! C bindings that mirror the Windows `PackageFamilyNameFromId` and
+//! `PackageNameAndPublisherIdFromFamilyName` functions, for calling this crate from C or C++ on
+//! any platform.
+//!
+//! Strings are NUL-terminated and UTF-8 encoded. Every function returns [`PFN_OK`] on success,
+//! [`PFN_INVALID_ARGUMENT`] if a pointer is null or an input is invalid, and
+//! [`PFN_INSUFFICIENT_BUFFER`] if an output buffer is too small, in which case nothing is
+//! written. A buffer of [`PFN_MAX_LENGTH`] bytes always fits a family name.
+
+use core::ffi::{c_char, CStr};
+
+use crate::{validate_package_name, PublisherId, MAX_PACKAGE_NAME_LEN};
+
+/// The function succeeded.
+pub const PFN_OK: i32 = 0;
+
+/// A pointer is null, a string isn't valid UTF-8, or an input isn't a valid package name or
+/// family name.
+pub const PFN_INVALID_ARGUMENT: i32 = -1;
+
+/// An output buffer is too small for the string and its NUL terminator.
+pub const PFN_INSUFFICIENT_BUFFER: i32 = -2;
+
+/// The size of a buffer that fits any family name, including its NUL terminator.
+pub const PFN_MAX_LENGTH: usize = MAX_PACKAGE_NAME_LEN + 1 + PublisherId::LENGTH + 1;
+
+/// Calculates the Package Family Name from an identity name and an identity publisher, writing it
+/// to `out`.
+///
+/// Unlike [`PackageFamilyName::new`](crate::PackageFamilyName::new), the identity name is
+/// validated, as it is by Windows.
+///
+/// # Safety
+///
+/// `name` and `publisher` must each be null or point to a NUL-terminated string, and `out` must
+/// be null or valid for writes of `out_len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn pfn_from_name_and_publisher(
+    name: *const c_char,
+    publisher: *const c_char,
+    out: *mut c_char,
+    out_len: usize,
+) -> i32 {
+    // SAFETY: the caller guarantees that both pointers are null or NUL-terminated
+    let (Some(name), Some(publisher)) = (unsafe { read_str(name) }, unsafe { read_str(publisher) })
+    else {
+        return PFN_INVALID_ARGUMENT;
+    };
+
+    if out.is_null() || validate_package_name(name).is_err() {
+        return PFN_INVALID_ARGUMENT;
+    }
+
+    let publisher_id = PublisherId::new(publisher);
+    let parts = [name, "_", publisher_id.as_str()];
+    if out_len <= c_str_len(&parts) {
+        return PFN_INSUFFICIENT_BUFFER;
+    }
+
+    // SAFETY: `out` is non-null and the caller guarantees it is valid for `out_len` bytes, which
+    // was just checked to fit the string
+    unsafe { write_c_str(&parts, out) };
+    PFN_OK
+}
+
+/// Splits a Package Family Name into its package name and Publisher Id, writing them to
+/// `name_out` and `publisher_id_out`.
+///
+/// The Publisher Id is written in its canonical lowercase form, whatever its case in
+/// `family_name`. Both buffers are checked before either is written, so on failure neither is
+/// modified. A `publisher_id_out` buffer of `PublisherId::LENGTH + 1`, 14 bytes, always fits the
+/// Publisher Id.
+///
+/// # Safety
+///
+/// `family_name` must be null or point to a NUL-terminated string, and `name_out` and
+/// `publisher_id_out` must each be null or valid for writes of `name_out_len` and
+/// `publisher_id_out_len` bytes respectively.
+#[no_mangle]
+pub unsafe extern "C" fn pfn_name_and_publisher_id(
+    family_name: *const c_char,
+    name_out: *mut c_char,
+    name_out_len: usize,
+    publisher_id_out: *mut c_char,
+    publisher_id_out_len: usize,
+) -> i32 {
+    // SAFETY: the caller guarantees that the pointer is null or NUL-terminated
+    let Some(family_name) = (unsafe { read_str(family_name) }) else {
+        return PFN_INVALID_ARGUMENT;
+    };
+
+    let Some((name, publisher_id)) = family_name.rsplit_once('_') else {
+        return PFN_INVALID_ARGUMENT;
+    };
+
+    if name_out.is_null() || publisher_id_out.is_null() || validate_package_name(name).is_err() {
+        return PFN_INVALID_ARGUMENT;
+    }
+
+    let Ok(publisher_id) = publisher_id.parse::<PublisherId>() else {
+        return PFN_INVALID_ARGUMENT;
+    };
+
+    if name_out_len <= name.len() || publisher_id_out_len <= PublisherId::LENGTH {
+        return PFN_INSUFFICIENT_BUFFER;
+    }
+
+    // SAFETY: both buffers are non-null and were just checked to fit their strings
+    unsafe {
+        write_c_str(&[name], name_out);
+        write_c_str(&[publisher_id.as_str()], publisher_id_out);
+    }
+    PFN_OK
+}
+
+/// Reads a NUL-terminated UTF-8 string, returning `None` if the pointer is null or the string
+/// isn't valid UTF-8.
+///
+/// # Safety
+///
+/// `s` must be null or point to a NUL-terminated string that outlives `'a`.
+unsafe fn read_str<'a>(s: *const c_char) -> Option<&'a str> {
+    if s.is_null() {
+        return None;
+    }
+
+    // SAFETY: the caller guarantees that `s` is NUL-terminated
+    unsafe { CStr::from_ptr(s) }.to_str().ok()
+}
+
+/// Returns the length of the concatenation of `parts`, not including the NUL terminator.
+fn c_str_len(parts: &[&str]) -> usize {
+    parts.iter().map(|part| part.len()).sum()
+}
+
+/// Writes the concatenation of `parts` followed by a NUL terminator to `out`.
+///
+/// # Safety
+///
+/// `out` must be valid for writes of `c_str_len(parts) + 1` bytes.
+unsafe fn write_c_str(parts: &[&str], out: *mut c_char) {
+    let len = c_str_len(parts);
+    // SAFETY: the caller guarantees that `out` is valid for `len + 1` bytes
+    let out = unsafe { core::slice::from_raw_parts_mut(out.cast::<u8>(), len + 1) };
+
+    let mut offset = 0;
+    for part in parts {
+        out[offset..offset + part.len()].copy_from_slice(part.as_bytes());
+        offset += part.len();
+    }
+    out[len] = 0;
+}
+
+#[cfg(test)]
+mod tests {
+    use core::ffi::{c_char, CStr};
+    use core::ptr;
+
+    use crate::ffi::{
+        pfn_from_name_and_publisher, pfn_name_and_publisher_id, PFN_INSUFFICIENT_BUFFER,
+        PFN_INVALID_ARGUMENT, PFN_MAX_LENGTH, PFN_OK,
+    };
+
+    fn buffer_str(buffer: &[c_char]) -> &str {
+        // SAFETY: the buffer was written by a function that NUL-terminates it
+        unsafe { CStr::from_ptr(buffer.as_ptr()) }.to_str().unwrap()
+    }
+
+    #[test]
+    fn test_pfn_from_name_and_publisher() {
+        let mut out = [0; PFN_MAX_LENGTH];
+
+        // SAFETY: the strings are NUL-terminated and the buffer is `out.len()` bytes
+        let result = unsafe {
+            pfn_from_name_and_publisher(
+                c"AppName".as_ptr(),
+                c"Publisher Software".as_ptr(),
+                out.as_mut_ptr(),
+                out.len(),
+            )
+        };
+
+        assert_eq!(result, PFN_OK);
+        assert_eq!(buffer_str(&out), "AppName_zj75k085cmj1a");
+    }
+
+    #[test]
+    fn test_pfn_from_name_and_publisher_buffer_size() {
+        // `AppName_zj75k085cmj1a` is 21 bytes, plus 1 for the NUL terminator
+        let mut out = [1; 22];
+        let call = |out: &mut [c_char]| unsafe {
+            // SAFETY: the strings are NUL-terminated and the buffer is `out.len()` bytes
+            pfn_from_name_and_publisher(
+                c"AppName".as_ptr(),
+                c"Publisher Software".as_ptr(),
+                out.as_mut_ptr(),
+                out.len(),
+            )
+        };
+
+        assert_eq!(call(&mut out[..21]), PFN_INSUFFICIENT_BUFFER);
+        assert_eq!(out, [1; 22]);
+        assert_eq!(call(&mut out), PFN_OK);
+        assert_eq!(buffer_str(&out), "AppName_zj75k085cmj1a");
+    }
+
+    #[test]
+    fn test_pfn_from_name_and_publisher_invalid_argument() {
+        let mut out = [0; PFN_MAX_LENGTH];
+
+        // SAFETY: every pointer is null or NUL-terminated, and the buffer is `out.len()` bytes
+        unsafe {
+            assert_eq!(
+                pfn_from_name_and_publisher(
+                    ptr::null(),
+                    c"CN=Contoso".as_ptr(),
+                    out.as_mut_ptr(),
+                    out.len()
+                ),
+                PFN_INVALID_ARGUMENT
+            );
+            assert_eq!(
+                pfn_from_name_and_publisher(
+                    c"Contoso/App".as_ptr(),
+                    c"CN=Contoso".as_ptr(),
+                    out.as_mut_ptr(),
+                    out.len()
+                ),
+                PFN_INVALID_ARGUMENT
+            );
+            assert_eq!(
+                pfn_from_name_and_publisher(
+                    c"Contoso.App".as_ptr(),
+                    c"CN=Contoso".as_ptr(),
+                    ptr::null_mut(),
+                    out.len()
+                ),
+                PFN_INVALID_ARGUMENT
+            );
+        }
+    }
+
+    #[test]
+    fn test_pfn_name_and_publisher_id() {
+        let mut name = [0; PFN_MAX_LENGTH];
+        let mut publisher_id = [0; 14];
+
+        // SAFETY: the string is NUL-terminated and each buffer is its own length
+        let result = unsafe {
+            pfn_name_and_publisher_id(
+                c"Microsoft.WindowsTerminal_8wekyb3d8bbwe".as_ptr(),
+                name.as_mut_ptr(),
+                name.len(),
+                publisher_id.as_mut_ptr(),
+                publisher_id.len(),
+            )
+        };
+
+        assert_eq!(result, PFN_OK);
+        assert_eq!(buffer_str(&name), "Microsoft.WindowsTerminal");
+        assert_eq!(buffer_str(&publisher_id), "8wekyb3d8bbwe");
+    }
+
+    #[test]
+    fn test_pfn_name_and_publisher_id_lowercases_publisher_id() {
+        let mut name = [0; PFN_MAX_LENGTH];
+        let mut publisher_id = [0; 14];
+
+        // SAFETY: the string is NUL-terminated and each buffer is its own length
+        let result = unsafe {
+            pfn_name_and_publisher_id(
+                c"Microsoft.WindowsTerminal_8WEKYB3D8BBWE".as_ptr(),
+                name.as_mut_ptr(),
+                name.len(),
+                publisher_id.as_mut_ptr(),
+                publisher_id.len(),
+            )
+        };
+
+        assert_eq!(result, PFN_OK);
+        assert_eq!(buffer_str(&name), "Microsoft.WindowsTerminal");
+        assert_eq!(buffer_str(&publisher_id), "8wekyb3d8bbwe");
+    }
+
+    #[test]
+    fn test_pfn_name_and_publisher_id_errors() {
+        let mut name = [1; PFN_MAX_LENGTH];
+        let mut publisher_id = [1; 13];
+        let mut call = |family_name: &CStr, publisher_id_len: usize| unsafe {
+            // SAFETY: the string is NUL-terminated and each buffer is at least its given length
+            pfn_name_and_publisher_id(
+                family_name.as_ptr(),
+                name.as_mut_ptr(),
+                PFN_MAX_LENGTH,
+                publisher_id.as_mut_ptr(),
+                publisher_id_len,
+            )
+        };
+
+        assert_eq!(
+            call(c"Microsoft.WindowsTerminal_8wekyb3d8bbwe", 13),
+            PFN_INSUFFICIENT_BUFFER
+        );
+        assert_eq!(call(c"Microsoft.WindowsTerminal", 13), PFN_INVALID_ARGUMENT);
+        assert_eq!(
+            call(c"Microsoft.WindowsTerminal_8wekyb3d8bbw", 13),
+            PFN_INVALID_ARGUMENT
+        );
+        assert_eq!(call(c"Contoso/App_8wekyb3d8bbwe", 13), PFN_INVALID_ARGUMENT);
+        assert_eq!(name, [1; PFN_MAX_LENGTH]);
+        assert_eq!(publisher_id, [1; 13]);
+    }
+}
@@ -1,29 +1,106 @@
 #![no_std]
 
+#[cfg(feature = "alloc")]
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
-use alloc::format;
-use alloc::string::String;
-use fast32::base32::CROCKFORD_LOWER;
-use sha2::{Digest, Sha256};
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+#[cfg(feature = "alloc")]
+mod audit;
+#[cfg(feature = "borsh")]
+mod borsh;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "std")]
+mod lines;
+#[cfg(feature = "alloc")]
+mod package_family_name;
+#[cfg(feature = "alloc")]
+mod package_full_name;
+mod package_name;
+mod package_sid;
+mod package_version;
+mod processor_architecture;
+pub mod publisher;
+pub mod publisher_id;
+#[cfg(feature = "schemars")]
+mod schemars;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(feature = "proptest")]
+pub mod strategy;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+#[cfg(feature = "alloc")]
+pub mod winget;
+#[cfg(feature = "x509")]
+mod x509;
 
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+
+#[cfg(feature = "alloc")]
+pub use audit::{audit, AuditWarning};
+#[cfg(feature = "std")]
+pub use lines::{parse_lines, ParseLinesError};
+#[cfg(feature = "alloc")]
+pub use package_family_name::{
+    is_valid_package_family_name, names_with_prefix, static_lookup, unique_publishers,
+    validate_all, Comparison, Descending, EscapedDisplay, PackageFamilyName,
+    PackageFamilyNameError, Relation,
+};
+#[cfg(feature = "alloc")]
+pub use package_full_name::{PackageFullName, PackageFullNameError, SegmentKind};
+#[cfg(feature = "alloc")]
+pub use package_name::PackageName;
+pub use package_name::{
+    validate_name_len, validate_package_name, PackageNameError, MAX_PACKAGE_NAME_LEN,
+    MIN_PACKAGE_NAME_LEN,
+};
+pub use package_sid::PackageSid;
+pub use package_version::{PackageVersion, PackageVersionError};
+pub use processor_architecture::{ProcessorArchitecture, ProcessorArchitectureError};
+pub use publisher_id::{
+    is_valid_publisher_id, publisher_digest, publisher_sha256, DigestTooShortError, PublisherId,
+    PublisherIdError,
+};
+#[cfg(feature = "x509")]
+pub use x509::CertError;
+
+#[cfg(feature = "alloc")]
 pub fn get_package_family_name(identity_name: &str, identity_publisher: &str) -> String {
-    let publisher_sha_256 = identity_publisher
-        .encode_utf16()
-        .flat_map(u16::to_le_bytes)
-        .fold(Sha256::new(), |buf, byte| buf.chain_update([byte]))
-        .finalize();
-
-    format!(
-        "{identity_name}_{}",
-        CROCKFORD_LOWER.encode(&publisher_sha_256[..8])
-    )
+    PackageFamilyName::new(identity_name, identity_publisher).to_string()
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "alloc"))]
 mod tests {
     use crate::get_package_family_name;
 
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_errors_implement_std_error() {
+        fn assert_std_error<E: std::error::Error + Send + Sync + 'static>() {}
+
+        assert_std_error::<crate::DigestTooShortError>();
+        assert_std_error::<crate::PackageFamilyNameError>();
+        assert_std_error::<crate::PackageFullNameError>();
+        assert_std_error::<crate::PackageNameError>();
+        assert_std_error::<crate::PackageVersionError>();
+        assert_std_error::<crate::ParseLinesError>();
+        assert_std_error::<crate::ProcessorArchitectureError>();
+        assert_std_error::<crate::PublisherIdError>();
+        #[cfg(feature = "x509")]
+        assert_std_error::<crate::CertError>();
+
+        let error: std::boxed::Box<dyn std::error::Error> = "Microsoft.WindowsTerminal"
+            .parse::<crate::PackageFamilyName>()
+            .unwrap_err()
+            .into();
+        assert!(error.source().is_none());
+    }
+
     #[test]
     fn test_package_family_name() {
         assert_eq!(
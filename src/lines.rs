@@ -0,0 +1,92 @@
+use std::io::{self, BufRead};
+
+use thiserror::Error;
+
+use crate::{PackageFamilyName, PackageFamilyNameError};
+
+#[derive(Error, Debug)]
+pub enum ParseLinesError {
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    #[error(transparent)]
+    PackageFamilyName(#[from] PackageFamilyNameError),
+}
+
+/// Parses a Package Family Name from each line of `reader`, one line at a time.
+///
+/// Each error is paired with its 1-based line number. Surrounding whitespace is trimmed and blank
+/// lines are skipped. Only the current line is held in memory, so arbitrarily large inputs can be
+/// processed.
+pub fn parse_lines<R: BufRead>(
+    reader: R,
+) -> impl Iterator<Item = Result<PackageFamilyName<'static>, (usize, ParseLinesError)>> {
+    reader.lines().enumerate().filter_map(|(index, line)| {
+        let line_number = index + 1;
+        let line = match line {
+            Ok(line) => line,
+            Err(error) => return Some(Err((line_number, error.into()))),
+        };
+
+        let line = line.trim();
+        (!line.is_empty()).then(|| {
+            line.parse()
+                .map_err(|error: PackageFamilyNameError| (line_number, error.into()))
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+    use std::vec::Vec;
+
+    use crate::lines::ParseLinesError;
+    use crate::{parse_lines, PackageFamilyName, PackageFamilyNameError, PublisherIdError};
+
+    #[test]
+    fn test_parse_lines() {
+        let input = "Microsoft.WindowsTerminal_8wekyb3d8bbwe\n\
+            Microsoft.PowerShell\n\
+            \n\
+            AppName_zj75k085cmj1a\r\n\
+            Contoso.App_8wekyb3d8bbw\n";
+
+        let results = parse_lines(Cursor::new(input)).collect::<Vec<_>>();
+
+        assert_eq!(results.len(), 4);
+        assert_eq!(
+            results[0].as_ref().unwrap(),
+            &PackageFamilyName::new(
+                "Microsoft.WindowsTerminal",
+                "CN=Microsoft Corporation, O=Microsoft Corporation, L=Redmond, S=Washington, C=US"
+            )
+        );
+        assert!(matches!(
+            results[1],
+            Err((
+                2,
+                ParseLinesError::PackageFamilyName(PackageFamilyNameError::NoUnderscore)
+            ))
+        ));
+        assert_eq!(
+            results[2].as_ref().unwrap(),
+            &PackageFamilyName::new("AppName", "Publisher Software")
+        );
+        assert!(matches!(
+            results[3],
+            Err((
+                5,
+                ParseLinesError::PackageFamilyName(PackageFamilyNameError::PublisherId(
+                    PublisherIdError::InvalidLength { actual: 12 }
+                ))
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_parse_lines_invalid_utf8() {
+        let results = parse_lines(Cursor::new(b"\xff\xfe\n")).collect::<Vec<_>>();
+
+        assert!(matches!(results[..], [Err((1, ParseLinesError::Io(_)))]));
+    }
+}
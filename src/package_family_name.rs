@@ -0,0 +1,1513 @@
+use alloc::borrow::{Cow, ToOwned};
+use alloc::collections::BTreeSet;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt::{self, Write};
+use core::hash::{Hash, Hasher};
+use core::str::FromStr;
+
+use thiserror::Error;
+
+use crate::publisher_id::cmp_ignore_ascii_case;
+use crate::{validate_package_name, PackageNameError, PackageSid, PublisherId, PublisherIdError};
+
+/// An MSIX Package Family Name, such as `Microsoft.WindowsTerminal_8wekyb3d8bbwe`.
+///
+/// Comparison, ordering and hashing are case-insensitive for both the package name and the
+/// Publisher Id, matching how Windows treats Package Family Names.
+///
+/// MSIX package names are restricted to ASCII, but [`new`](Self::new) doesn't validate, so package
+/// names are compared by their Unicode lowercase mapping, making `Café` equal to `CAFÉ`. This
+/// maps each character on its own and doesn't fold `ß` to `ss`; enable the `unicode` feature for
+/// [`eq_unicode_ci`](Self::eq_unicode_ci) if full case folding is needed.
+///
+/// The package name may be borrowed or owned. Cloning a family name with a borrowed package name
+/// doesn't allocate, while cloning one with an owned package name copies it.
+#[derive(Clone, Debug)]
+pub struct PackageFamilyName<'ident> {
+    package_name: Cow<'ident, str>,
+    publisher_id: PublisherId,
+}
+
+impl<'ident> PackageFamilyName<'ident> {
+    /// Calculates the Package Family Name from an identity name and an identity publisher.
+    ///
+    /// The identity name is stored as given and is not validated.
+    pub fn new(
+        identity_name: impl Into<Cow<'ident, str>>,
+        identity_publisher: impl AsRef<str>,
+    ) -> Self {
+        Self {
+            package_name: identity_name.into(),
+            publisher_id: PublisherId::new(identity_publisher),
+        }
+    }
+
+    /// Calculates the Package Family Name like [`new`](Self::new), but first validates the
+    /// identity name with [`validate_package_name`], for names that come from untrusted input.
+    ///
+    /// The name isn't trimmed, so surrounding whitespace is an invalid character. Use
+    /// [`try_new_reporting`](Self::try_new_reporting) to trim it instead.
+    ///
+    /// ```
+    /// use package_family_name::{PackageFamilyName, PackageFamilyNameError, PackageNameError};
+    ///
+    /// assert_eq!(
+    ///     PackageFamilyName::try_new("Contoso/App", "CN=Contoso"),
+    ///     Err(PackageFamilyNameError::InvalidPackageName(
+    ///         PackageNameError::InvalidCharacter { character: '/' }
+    ///     ))
+    /// );
+    /// ```
+    pub fn try_new(
+        identity_name: impl Into<Cow<'ident, str>>,
+        identity_publisher: impl AsRef<str>,
+    ) -> Result<Self, PackageFamilyNameError> {
+        let identity_name = identity_name.into();
+        validate_package_name(&identity_name)?;
+
+        Ok(Self::new(identity_name, identity_publisher))
+    }
+
+    /// Calculates the Package Family Name like [`try_new`](Self::try_new), but trims the identity
+    /// name before validating it with [`validate_package_name`], returning whether the name had
+    /// to be changed.
+    ///
+    /// The only transformation is trimming leading and trailing whitespace, so the flag is `true`
+    /// exactly when the name had surrounding whitespace. Tools can use it to warn that a name was
+    /// corrected rather than silently accepting it.
+    pub fn try_new_reporting(
+        identity_name: impl Into<Cow<'ident, str>>,
+        identity_publisher: impl AsRef<str>,
+    ) -> Result<(Self, bool), PackageFamilyNameError> {
+        let identity_name = identity_name.into();
+        let modified = identity_name.trim().len() != identity_name.len();
+
+        let package_name = match identity_name {
+            Cow::Borrowed(name) => Cow::Borrowed(name.trim()),
+            Cow::Owned(name) if modified => Cow::Owned(name.trim().to_owned()),
+            owned => owned,
+        };
+        validate_package_name(&package_name)?;
+
+        Ok((
+            Self {
+                package_name,
+                publisher_id: PublisherId::new(identity_publisher),
+            },
+            modified,
+        ))
+    }
+
+    /// Creates a family name from an owned package name and a Publisher Id, taking ownership of the
+    /// `String` without copying it.
+    ///
+    /// Like [`new`](Self::new), the package name is not validated.
+    #[inline]
+    pub fn from_owned_parts(
+        package_name: String,
+        publisher_id: PublisherId,
+    ) -> PackageFamilyName<'static> {
+        PackageFamilyName {
+            package_name: Cow::Owned(package_name),
+            publisher_id,
+        }
+    }
+
+    /// Creates a family name from a package name and an already calculated Publisher Id, the
+    /// inverse of [`package_name`](Self::package_name) and [`publisher_id`](Self::publisher_id).
+    ///
+    /// Like [`new`](Self::new), the package name is not validated, and a borrowed name isn't
+    /// copied.
+    ///
+    /// ```
+    /// use package_family_name::{PackageFamilyName, PublisherId};
+    ///
+    /// let publisher_id = "8wekyb3d8bbwe".parse::<PublisherId>().unwrap();
+    /// let package_family_name =
+    ///     PackageFamilyName::from_parts("Microsoft.WindowsTerminal", publisher_id);
+    ///
+    /// assert_eq!(
+    ///     package_family_name.to_string(),
+    ///     "Microsoft.WindowsTerminal_8wekyb3d8bbwe"
+    /// );
+    /// assert!(package_family_name.is_name_borrowed());
+    /// ```
+    #[inline]
+    pub fn from_parts(
+        package_name: impl Into<Cow<'ident, str>>,
+        publisher_id: PublisherId,
+    ) -> Self {
+        Self {
+            package_name: package_name.into(),
+            publisher_id,
+        }
+    }
+
+    /// Parses a Package Family Name, borrowing the package name from `s`.
+    ///
+    /// This is the same as [`str::parse`] except that the package name isn't copied, which saves
+    /// an allocation per name when parsing many names whose source outlives them.
+    ///
+    /// ```
+    /// use package_family_name::PackageFamilyName;
+    ///
+    /// let package_family_name = PackageFamilyName::parse("AppName_zj75k085cmj1a").unwrap();
+    ///
+    /// assert_eq!(package_family_name.package_name(), "AppName");
+    /// assert!(package_family_name.is_name_borrowed());
+    /// ```
+    #[inline]
+    pub fn parse(s: &'ident str) -> Result<Self, PackageFamilyNameError> {
+        Self::try_from(s)
+    }
+
+    /// Extracts the family name from a Package Full Name, such as
+    /// `Microsoft.Windows.Photos_2021.21090.10012.0_x64__8wekyb3d8bbwe`, like Windows'
+    /// `PackageFamilyNameFromFullName`.
+    ///
+    /// The full name must have five underscore-separated segments, so a family name on its own is
+    /// an error. Only the first segment, the package name, and the last segment, the Publisher
+    /// Id, are used; the version, architecture and resource id in between aren't validated. Use
+    /// [`PackageFullName`](crate::PackageFullName) to parse every segment.
+    ///
+    /// ```
+    /// use package_family_name::PackageFamilyName;
+    ///
+    /// let package_family_name = PackageFamilyName::from_full_name(
+    ///     "Microsoft.Windows.Photos_2021.21090.10012.0_x64__8wekyb3d8bbwe",
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(package_family_name, "Microsoft.Windows.Photos_8wekyb3d8bbwe");
+    /// ```
+    pub fn from_full_name(full_name: &'ident str) -> Result<Self, PackageFamilyNameError> {
+        let (package_name, _) = full_name
+            .split_once('_')
+            .ok_or(PackageFamilyNameError::NoUnderscore)?;
+        if full_name.split('_').count() != 5 {
+            return Err(PackageFamilyNameError::WrongSegmentCount);
+        }
+        if package_name.is_empty() {
+            return Err(PackageFamilyNameError::EmptyPackageName);
+        }
+        let (_, publisher_id) = split(full_name)?;
+
+        Ok(Self {
+            package_name: Cow::Borrowed(package_name),
+            publisher_id,
+        })
+    }
+
+    /// Returns the package name, the part before the underscore.
+    #[inline]
+    pub fn package_name(&self) -> &str {
+        &self.package_name
+    }
+
+    /// Calculates the AppContainer SID of the package, the `S-1-15-2-…` identifier that Windows
+    /// uses for it in access control lists and firewall rules.
+    ///
+    /// ```
+    /// use package_family_name::PackageFamilyName;
+    ///
+    /// let package_family_name =
+    ///     PackageFamilyName::parse("Microsoft.MicrosoftEdge_8wekyb3d8bbwe").unwrap();
+    ///
+    /// assert_eq!(
+    ///     package_family_name.to_package_sid().to_string(),
+    ///     "S-1-15-2-3624051433-2125758914-1423191267-1740899205-1073925389-3782572162-737981194"
+    /// );
+    /// ```
+    pub fn to_package_sid(&self) -> PackageSid {
+        PackageSid::from_chars(
+            lowercase(&self.package_name)
+                .chain(core::iter::once('_'))
+                .chain(self.publisher_id.as_str().chars())
+                .map(|character| character.to_ascii_lowercase()),
+        )
+    }
+
+    /// Returns the name of the directory that holds the package's per-user state, under
+    /// `%LOCALAPPDATA%\Packages\`.
+    ///
+    /// This is the [`Display`](fmt::Display) form of the family name, with the casing it was
+    /// created with. The file system is case-insensitive, so the casing doesn't matter for finding
+    /// the directory. It is returned as a `String` because the family name stores its two parts
+    /// separately. Named objects aren't stored by family name but by
+    /// [package SID](Self::to_package_sid), in
+    /// [`PackageSid::named_objects_directory`].
+    ///
+    /// ```
+    /// use package_family_name::PackageFamilyName;
+    ///
+    /// let package_family_name =
+    ///     PackageFamilyName::parse("Microsoft.WindowsTerminal_8wekyb3d8bbwe").unwrap();
+    ///
+    /// assert_eq!(
+    ///     package_family_name.local_folder_name(),
+    ///     "Microsoft.WindowsTerminal_8wekyb3d8bbwe"
+    /// );
+    /// ```
+    #[inline]
+    pub fn local_folder_name(&self) -> String {
+        self.to_string()
+    }
+
+    /// Returns whether the package name is borrowed rather than owned, for checking that a family
+    /// name was built without allocating.
+    #[inline]
+    pub const fn is_name_borrowed(&self) -> bool {
+        matches!(self.package_name, Cow::Borrowed(_))
+    }
+
+    /// Returns the Publisher Id, the part after the underscore.
+    ///
+    /// This is the way to key collections of family names by publisher, such as a
+    /// `HashSet<PublisherId>` built with `.map(|family_name| family_name.publisher_id().clone())`.
+    /// `PackageFamilyName` doesn't implement `Borrow<PublisherId>`, because family names with the
+    /// same Publisher Id but different package names are not equal and don't hash the same, which
+    /// `Borrow` requires.
+    #[inline]
+    pub const fn publisher_id(&self) -> &PublisherId {
+        &self.publisher_id
+    }
+
+    /// Returns the package name and the Publisher Id together.
+    ///
+    /// ```
+    /// use package_family_name::PackageFamilyName;
+    ///
+    /// let package_family_name = PackageFamilyName::new("AppName", "Publisher Software");
+    /// let (name, publisher_id) = package_family_name.as_parts();
+    ///
+    /// assert_eq!(name, "AppName");
+    /// assert_eq!(publisher_id.as_str(), "zj75k085cmj1a");
+    /// ```
+    #[inline]
+    pub fn as_parts(&self) -> (&str, &PublisherId) {
+        (&self.package_name, &self.publisher_id)
+    }
+
+    /// Converts the family name into one that owns its package name, copying the name if it was
+    /// borrowed, so that it can outlive the string it was created from.
+    #[inline]
+    pub fn into_owned(self) -> PackageFamilyName<'static> {
+        PackageFamilyName {
+            package_name: Cow::Owned(self.package_name.into_owned()),
+            publisher_id: self.publisher_id,
+        }
+    }
+
+    /// Returns the canonical form of the family name, with both the package name and the Publisher
+    /// Id in lowercase.
+    ///
+    /// Family names that are equal produce byte-for-byte identical normalized values, which makes
+    /// this suitable as an owned key, such as in a `HashMap`.
+    pub fn normalize(&self) -> PackageFamilyName<'static> {
+        PackageFamilyName {
+            package_name: Cow::Owned(lowercase(&self.package_name).collect()),
+            publisher_id: self.publisher_id.clone(),
+        }
+    }
+
+    /// Returns whether this family name is equal to another using full Unicode case folding for
+    /// the package name, rather than the per-character lowercase mapping of [`PartialEq`].
+    ///
+    /// This is only useful for non-standard package names containing non-ASCII characters, such
+    /// as `Straße` and `STRASSE`, which are only equal with full case folding.
+    #[cfg(feature = "unicode")]
+    pub fn eq_unicode_ci(&self, other: &Self) -> bool {
+        unicase::UniCase::unicode(&*self.package_name)
+            == unicase::UniCase::unicode(&*other.package_name)
+            && self.publisher_id == other.publisher_id
+    }
+
+    /// Returns whether this family name is equal to another including the case of the package
+    /// name, unlike [`PartialEq`], which ignores it.
+    #[inline]
+    pub fn eq_case_sensitive(&self, other: &Self) -> bool {
+        self.package_name == other.package_name && self.publisher_id == other.publisher_id
+    }
+
+    /// Classifies how this family name relates to another, for grouping family names by package
+    /// name and then by publisher.
+    pub fn relation(&self, other: &Self) -> Relation {
+        if !eq_ignore_case(&self.package_name, &other.package_name) {
+            Relation::Different
+        } else if self.publisher_id == other.publisher_id {
+            Relation::Same
+        } else {
+            Relation::SameNameDifferentPublisher
+        }
+    }
+
+    /// Compares this family name with another, reporting which of its parts are equal.
+    ///
+    /// Both parts are compared ignoring case, the same as [`PartialEq`].
+    pub fn compare(&self, other: &Self) -> Comparison {
+        let name_equal = eq_ignore_case(&self.package_name, &other.package_name);
+        let publisher_equal = self.publisher_id == other.publisher_id;
+
+        Comparison {
+            name_equal,
+            publisher_equal,
+            equal: name_equal && publisher_equal,
+        }
+    }
+
+    /// Returns the family name as an owned [`Cow`], for passing to APIs that take `Cow<str>`.
+    ///
+    /// This always allocates, as the two halves of the family name aren't stored together.
+    ///
+    /// ```
+    /// use std::borrow::Cow;
+    ///
+    /// use package_family_name::PackageFamilyName;
+    ///
+    /// fn label(text: Cow<'static, str>) -> usize {
+    ///     text.len()
+    /// }
+    ///
+    /// let package_family_name = PackageFamilyName::new("AppName", "Publisher Software");
+    ///
+    /// assert_eq!(label(package_family_name.to_cow_string()), "AppName_zj75k085cmj1a".len());
+    /// ```
+    #[inline]
+    pub fn to_cow_string(&self) -> Cow<'static, str> {
+        Cow::Owned(self.to_string())
+    }
+
+    /// Returns the package name with the first letter of each dot-separated segment uppercased,
+    /// such as `Microsoft.Windows.Photos` for `microsoft.windows.photos`.
+    ///
+    /// This is purely cosmetic for displaying names. The family name itself is unchanged, and it
+    /// compares equal regardless of case anyway.
+    pub fn display_name_titlecase(&self) -> String {
+        let mut titlecase = String::with_capacity(self.package_name.len());
+
+        for (index, segment) in self.package_name.split('.').enumerate() {
+            if index > 0 {
+                titlecase.push('.');
+            }
+
+            let mut chars = segment.chars();
+            if let Some(first) = chars.next() {
+                titlecase.push(first.to_ascii_uppercase());
+                titlecase.push_str(chars.as_str());
+            }
+        }
+
+        titlecase
+    }
+
+    /// Returns a shortened form of the family name for compact log lines, such as
+    /// `M.W.Photos_8wek…` for `Microsoft.Windows.Photos_8wekyb3d8bbwe`, that is at most
+    /// `max_width` characters long.
+    ///
+    /// The rules are applied in order until the result fits:
+    ///
+    /// 1. The family name is returned unchanged if it already fits.
+    /// 2. Every dot-separated segment of the package name except the last is abbreviated to its
+    ///    first character.
+    /// 3. The end is truncated and replaced with `…`, cutting into the Publisher Id first.
+    ///
+    /// This is purely cosmetic: the result is lossy and must never be parsed back.
+    pub fn short_display(&self, max_width: usize) -> String {
+        let full = self.to_string();
+        if full.chars().count() <= max_width {
+            return full;
+        }
+
+        let mut short = String::new();
+        let mut segments = self.package_name.split('.').peekable();
+        while let Some(segment) = segments.next() {
+            if segments.peek().is_some() {
+                short.extend(segment.chars().next());
+                short.push('.');
+            } else {
+                short.push_str(segment);
+            }
+        }
+        let _ = write!(short, "_{}", self.publisher_id);
+
+        if short.chars().count() > max_width {
+            short = short.chars().take(max_width.saturating_sub(1)).collect();
+            if max_width > 0 {
+                short.push('…');
+            }
+        }
+
+        short
+    }
+
+    /// Returns a wrapper whose [`Display`](fmt::Display) escapes control characters in the package
+    /// name, for logging names that were created from untrusted input with [`new`](Self::new).
+    ///
+    /// Control characters below `U+0080` are written as `\xNN`, and any others as `\u{NNNN}`.
+    #[inline]
+    pub const fn escaped_display(&self) -> EscapedDisplay<'_, 'ident> {
+        EscapedDisplay(self)
+    }
+}
+
+impl fmt::Display for PackageFamilyName<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}_{}", self.package_name, self.publisher_id)
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for PackageFamilyName<'_> {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "{=str}_{}", self.package_name(), self.publisher_id);
+    }
+}
+
+/// How two family names relate to each other, as returned by [`PackageFamilyName::relation`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Relation {
+    /// The family names are equal.
+    Same,
+    /// The package names are equal but the Publisher Ids are different.
+    SameNameDifferentPublisher,
+    /// The package names are different.
+    Different,
+}
+
+/// The result of comparing two family names part by part, as returned by
+/// [`PackageFamilyName::compare`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Comparison {
+    /// Whether the package names are equal.
+    pub name_equal: bool,
+    /// Whether the Publisher Ids are equal.
+    pub publisher_equal: bool,
+    /// Whether the family names as a whole are equal.
+    pub equal: bool,
+}
+
+/// Displays a [`PackageFamilyName`] with control characters escaped.
+///
+/// Created by [`PackageFamilyName::escaped_display`].
+#[derive(Clone, Copy, Debug)]
+pub struct EscapedDisplay<'a, 'ident>(&'a PackageFamilyName<'ident>);
+
+impl fmt::Display for EscapedDisplay<'_, '_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for character in self.0.package_name.chars() {
+            match character {
+                '\0'..='\x7f' if character.is_control() => {
+                    write!(f, "\\x{:02x}", character as u32)?
+                }
+                _ if character.is_control() => write!(f, "\\u{{{:04x}}}", character as u32)?,
+                _ => f.write_char(character)?,
+            }
+        }
+        write!(f, "_{}", self.0.publisher_id)
+    }
+}
+
+impl AsRef<PublisherId> for PackageFamilyName<'_> {
+    #[inline]
+    fn as_ref(&self) -> &PublisherId {
+        &self.publisher_id
+    }
+}
+
+impl PartialEq for PackageFamilyName<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        // The Publisher Id is fixed-length, so comparing it first fails fast for different
+        // publishers without walking a potentially long package name
+        self.publisher_id == other.publisher_id
+            && eq_ignore_case(&self.package_name, &other.package_name)
+    }
+}
+
+impl Eq for PackageFamilyName<'_> {}
+
+/// Compares against a family name string, ignoring case. Strings that don't parse as a
+/// family name are never equal.
+impl PartialEq<str> for PackageFamilyName<'_> {
+    fn eq(&self, other: &str) -> bool {
+        split(other).is_ok_and(|(package_name, publisher_id)| {
+            self.publisher_id == publisher_id && eq_ignore_case(&self.package_name, package_name)
+        })
+    }
+}
+
+impl PartialEq<&str> for PackageFamilyName<'_> {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+impl PartialOrd for PackageFamilyName<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for PackageFamilyName<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        cmp_ignore_case(&self.package_name, &other.package_name)
+            .then_with(|| self.publisher_id.cmp(&other.publisher_id))
+    }
+}
+
+impl Hash for PackageFamilyName<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for character in lowercase(&self.package_name) {
+            state.write_u32(character.into());
+        }
+        state.write_u8(0xff);
+        self.publisher_id.hash(state);
+    }
+}
+
+/// A [`PackageFamilyName`] that sorts in descending order, so that sorting a
+/// `Vec<Descending>` lists family names from Z to A.
+///
+/// Equality and hashing are the same as for the wrapped family name.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Descending<'ident>(pub PackageFamilyName<'ident>);
+
+impl PartialOrd for Descending<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Descending<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.0.cmp(&self.0)
+    }
+}
+
+/// Errors from parsing or validating a Package Family Name.
+///
+/// This is the one error type for a whole family name: the errors for its parts,
+/// [`PackageNameError`] and [`PublisherIdError`], convert into it with `?`.
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PackageFamilyNameError {
+    /// There is no underscore separating the package name from the Publisher Id.
+    #[error("Package Family Name must contain an underscore between the package name and the Publisher Id")]
+    NoUnderscore,
+    /// A full name passed to [`PackageFamilyName::from_full_name`] doesn't have five
+    /// underscore-separated segments.
+    #[error("Package Full Name must have five underscore-separated segments: package name, version, architecture, resource id and Publisher Id")]
+    WrongSegmentCount,
+    /// There is nothing before the underscore, so the package name is empty.
+    #[error("Package Family Name must have a package name before the underscore")]
+    EmptyPackageName,
+    /// The package name isn't a valid MSIX package name.
+    #[error(transparent)]
+    InvalidPackageName(#[from] PackageNameError),
+    /// The Publisher Id isn't valid, as described by [`PublisherIdError`].
+    #[error(transparent)]
+    PublisherId(#[from] PublisherIdError),
+}
+
+impl FromStr for PackageFamilyName<'_> {
+    type Err = PackageFamilyNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (package_name, publisher_id) = split(s)?;
+
+        Ok(Self {
+            package_name: package_name.to_owned().into(),
+            publisher_id,
+        })
+    }
+}
+
+/// Parses a family name like [`FromStr`], but borrows the package name from `s` rather than
+/// allocating.
+impl<'ident> TryFrom<&'ident str> for PackageFamilyName<'ident> {
+    type Error = PackageFamilyNameError;
+
+    fn try_from(s: &'ident str) -> Result<Self, Self::Error> {
+        let (package_name, publisher_id) = split(s)?;
+
+        Ok(Self {
+            package_name: Cow::Borrowed(package_name),
+            publisher_id,
+        })
+    }
+}
+
+/// Returns whether `s` parses as a family name, the same as `s.parse::<PackageFamilyName>()`
+/// succeeding but without allocating the package name or an error.
+///
+/// Like parsing, this checks that there is a non-empty package name and a valid Publisher Id, but
+/// doesn't check the package name against the MSIX naming rules; use [`validate_package_name`] on
+/// the [`package_name`](PackageFamilyName::package_name) for that.
+///
+/// ```
+/// use package_family_name::is_valid_package_family_name;
+///
+/// assert!(is_valid_package_family_name("Microsoft.WindowsTerminal_8wekyb3d8bbwe"));
+/// assert!(!is_valid_package_family_name("Microsoft.WindowsTerminal"));
+/// ```
+#[inline]
+pub fn is_valid_package_family_name(s: &str) -> bool {
+    split(s).is_ok()
+}
+
+/// Splits a family name into its package name and parsed Publisher Id.
+fn split(s: &str) -> Result<(&str, PublisherId), PackageFamilyNameError> {
+    let (package_name, publisher_id) = s
+        .rsplit_once('_')
+        .ok_or(PackageFamilyNameError::NoUnderscore)?;
+
+    if package_name.is_empty() {
+        return Err(PackageFamilyNameError::EmptyPackageName);
+    }
+
+    Ok((package_name, publisher_id.parse()?))
+}
+
+/// Parses every family name in `names`, returning each error with the index of the name that
+/// caused it, so that all problems can be reported at once.
+pub fn validate_all(names: &[&str]) -> Vec<(usize, PackageFamilyNameError)> {
+    names
+        .iter()
+        .enumerate()
+        .filter_map(|(index, name)| {
+            name.parse::<PackageFamilyName>()
+                .err()
+                .map(|error| (index, error))
+        })
+        .collect()
+}
+
+/// Returns each distinct Publisher Id in `family_names` once, in the order it was first seen.
+///
+/// Publisher Ids are compared ignoring case. The crate is `no_std`, so the ids seen so far are
+/// tracked in a [`BTreeSet`] using their ordering rather than in a hash set.
+pub fn unique_publishers<'ident, I>(
+    family_names: I,
+) -> impl Iterator<Item = PublisherId> + use<'ident, I>
+where
+    I: IntoIterator<Item = PackageFamilyName<'ident>>,
+{
+    let mut seen = BTreeSet::new();
+
+    family_names
+        .into_iter()
+        .map(|family_name| family_name.publisher_id)
+        .filter(move |publisher_id| seen.insert(publisher_id.clone()))
+}
+
+/// Returns the sub-slice of `sorted` whose package names start with `prefix`, ignoring case.
+///
+/// `sorted` must be sorted by the [`Ord`] implementation of [`PackageFamilyName`]. Family names
+/// sharing a prefix are contiguous under that ordering, so the range is found with two binary
+/// searches rather than a linear scan.
+pub fn names_with_prefix<'a, 'ident>(
+    sorted: &'a [PackageFamilyName<'ident>],
+    prefix: &str,
+) -> &'a [PackageFamilyName<'ident>] {
+    let start = sorted.partition_point(|family_name| {
+        cmp_ignore_case(family_name.package_name(), prefix) == Ordering::Less
+    });
+
+    let len = sorted[start..]
+        .partition_point(|family_name| starts_with_ignore_case(family_name.package_name(), prefix));
+
+    &sorted[start..start + len]
+}
+
+/// Looks up the value for `family_name` in a table of `(PackageFamilyName, V)` pairs, ignoring
+/// case.
+///
+/// `entries` must be sorted by family name, for example with `entries.sort_by(|a, b| a.0.cmp(&b.0))`.
+/// Because the comparison is case-insensitive, this is safer than matching on family name
+/// strings and avoids hashing for small fixed sets of known packages.
+pub fn static_lookup<'a, V>(
+    entries: &'a [(PackageFamilyName<'_>, V)],
+    family_name: &PackageFamilyName<'_>,
+) -> Option<&'a V> {
+    entries
+        .binary_search_by(|(entry, _)| entry.cmp(family_name))
+        .ok()
+        .map(|index| &entries[index].1)
+}
+
+/// Returns whether two package names are equal by their Unicode lowercase mapping, comparing
+/// bytes directly when both are ASCII.
+pub(crate) fn eq_ignore_case(left: &str, right: &str) -> bool {
+    if left.is_ascii() && right.is_ascii() {
+        left.eq_ignore_ascii_case(right)
+    } else {
+        lowercase(left).eq(lowercase(right))
+    }
+}
+
+/// Orders two package names by their Unicode lowercase mapping, consistently with
+/// [`eq_ignore_case`].
+fn cmp_ignore_case(left: &str, right: &str) -> Ordering {
+    if left.is_ascii() && right.is_ascii() {
+        cmp_ignore_ascii_case(left, right)
+    } else {
+        lowercase(left).cmp(lowercase(right))
+    }
+}
+
+/// Returns whether `s` starts with `prefix` by their Unicode lowercase mapping.
+fn starts_with_ignore_case(s: &str, prefix: &str) -> bool {
+    let mut s = lowercase(s);
+    lowercase(prefix).all(|character| s.next() == Some(character))
+}
+
+/// Maps each character of `s` to its Unicode lowercase form.
+fn lowercase(s: &str) -> impl Iterator<Item = char> + '_ {
+    s.chars().flat_map(char::to_lowercase)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::borrow::Cow;
+    use alloc::string::{String, ToString};
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use core::cmp::Ordering;
+
+    use crate::{
+        is_valid_package_family_name, names_with_prefix, static_lookup, unique_publishers,
+        validate_all, validate_package_name, Comparison, Descending, PackageFamilyName,
+        PackageFamilyNameError, PackageFullName, PackageNameError, PublisherId, PublisherIdError,
+        Relation,
+    };
+
+    const MICROSOFT: &str =
+        "CN=Microsoft Corporation, O=Microsoft Corporation, L=Redmond, S=Washington, C=US";
+
+    #[test]
+    fn test_package_family_name() {
+        let package_family_name = PackageFamilyName::new("Microsoft.WindowsTerminal", MICROSOFT);
+
+        assert_eq!(
+            package_family_name.to_string(),
+            "Microsoft.WindowsTerminal_8wekyb3d8bbwe"
+        );
+        assert_eq!(
+            "MICROSOFT.WINDOWSTERMINAL_8WEKYB3D8BBWE".parse(),
+            Ok(package_family_name)
+        );
+    }
+
+    #[test]
+    fn test_partial_eq_str() {
+        let package_family_name = PackageFamilyName::new("Microsoft.PowerShell", MICROSOFT);
+
+        assert_eq!(package_family_name, "Microsoft.PowerShell_8wekyb3d8bbwe");
+        assert_eq!(package_family_name, *"MICROSOFT.POWERSHELL_8WEKYB3D8BBWE");
+        assert_ne!(package_family_name, "Microsoft.PowerShell_h91ms92gdsmmt");
+        assert_ne!(
+            package_family_name,
+            "Microsoft.WindowsTerminal_8wekyb3d8bbwe"
+        );
+        assert_ne!(package_family_name, "Microsoft.PowerShell");
+        assert_ne!(package_family_name, "Microsoft.PowerShell_8wekyb3d8bbw");
+    }
+
+    #[test]
+    fn test_package_name_with_underscore() {
+        let package_family_name = "My_App_8wekyb3d8bbwe".parse::<PackageFamilyName>().unwrap();
+
+        assert_eq!(package_family_name.package_name(), "My_App");
+        assert_eq!(package_family_name.publisher_id().as_str(), "8wekyb3d8bbwe");
+        assert_eq!(
+            PackageFamilyName::parse("My_App_8wekyb3d8bbwe"),
+            Ok(package_family_name)
+        );
+    }
+
+    #[test]
+    fn test_try_from_str() {
+        let s = "Microsoft.WindowsTerminal_8wekyb3d8bbwe";
+        let package_family_name = PackageFamilyName::try_from(s).unwrap();
+
+        assert!(package_family_name.is_name_borrowed());
+        assert_eq!(package_family_name.package_name().as_ptr(), s.as_ptr());
+        assert_eq!(package_family_name, s.parse::<PackageFamilyName>().unwrap());
+        assert_eq!(
+            PackageFamilyName::try_from("Microsoft.WindowsTerminal"),
+            Err(PackageFamilyNameError::NoUnderscore)
+        );
+    }
+
+    #[test]
+    fn test_from_full_name() {
+        let full_name = "Microsoft.Windows.Photos_2021.21090.10012.0_x64__8wekyb3d8bbwe";
+        let package_family_name = PackageFamilyName::from_full_name(full_name).unwrap();
+
+        assert_eq!(
+            package_family_name.package_name(),
+            "Microsoft.Windows.Photos"
+        );
+        assert_eq!(package_family_name.publisher_id(), "8wekyb3d8bbwe");
+        assert!(package_family_name.is_name_borrowed());
+        assert_eq!(
+            package_family_name,
+            PackageFullName::parse(full_name).unwrap().family_name()
+        );
+        assert_eq!(
+            PackageFamilyName::from_full_name("Microsoft.Windows.Photos"),
+            Err(PackageFamilyNameError::NoUnderscore)
+        );
+        assert_eq!(
+            PackageFamilyName::from_full_name("_2021.21090.10012.0_x64__8wekyb3d8bbwe"),
+            Err(PackageFamilyNameError::EmptyPackageName)
+        );
+        assert_eq!(
+            PackageFamilyName::from_full_name("Microsoft.Windows.Photos_2021.21090.10012.0_x64__"),
+            Err(PackageFamilyNameError::PublisherId(
+                PublisherIdError::InvalidLength { actual: 0 }
+            ))
+        );
+    }
+
+    #[test]
+    fn test_from_full_name_wrong_segment_count() {
+        for full_name in [
+            "Microsoft.Windows.Photos_8wekyb3d8bbwe",
+            "Microsoft.Windows.Photos_2021.21090.10012.0_x64_8wekyb3d8bbwe",
+            "Microsoft_Windows.Photos_2021.21090.10012.0_x64__8wekyb3d8bbwe",
+        ] {
+            assert_eq!(
+                PackageFamilyName::from_full_name(full_name),
+                Err(PackageFamilyNameError::WrongSegmentCount)
+            );
+        }
+    }
+
+    #[test]
+    fn test_package_family_name_from_str_errors() {
+        assert_eq!(
+            "Microsoft.WindowsTerminal".parse::<PackageFamilyName>(),
+            Err(PackageFamilyNameError::NoUnderscore)
+        );
+        assert_eq!(
+            "_8wekyb3d8bbwe".parse::<PackageFamilyName>(),
+            Err(PackageFamilyNameError::EmptyPackageName)
+        );
+        assert_eq!(
+            PackageFamilyName::parse("_8wekyb3d8bbwe"),
+            Err(PackageFamilyNameError::EmptyPackageName)
+        );
+        assert_eq!(
+            "Microsoft.WindowsTerminal_8wekyb3d".parse::<PackageFamilyName>(),
+            Err(PackageFamilyNameError::PublisherId(
+                PublisherIdError::InvalidLength { actual: 8 }
+            ))
+        );
+    }
+
+    #[test]
+    fn test_try_new() {
+        assert_eq!(
+            PackageFamilyName::try_new("Microsoft.WindowsTerminal", MICROSOFT),
+            Ok(PackageFamilyName::new(
+                "Microsoft.WindowsTerminal",
+                MICROSOFT
+            ))
+        );
+        assert_eq!(
+            PackageFamilyName::try_new("Windows Terminal", MICROSOFT),
+            Err(PackageFamilyNameError::InvalidPackageName(
+                PackageNameError::InvalidCharacter { character: ' ' }
+            ))
+        );
+        assert_eq!(
+            PackageFamilyName::try_new("a".repeat(51), MICROSOFT),
+            Err(PackageFamilyNameError::InvalidPackageName(
+                PackageNameError::InvalidLength
+            ))
+        );
+    }
+
+    #[test]
+    fn test_try_new_rejects_surrounding_whitespace() {
+        assert_eq!(
+            PackageFamilyName::try_new(" Microsoft.WindowsTerminal ", MICROSOFT),
+            Err(PackageFamilyNameError::InvalidPackageName(
+                PackageNameError::InvalidCharacter { character: ' ' }
+            ))
+        );
+        assert_eq!(
+            PackageFamilyName::try_new_reporting(" Microsoft.WindowsTerminal ", MICROSOFT),
+            Ok((
+                PackageFamilyName::new("Microsoft.WindowsTerminal", MICROSOFT),
+                true
+            ))
+        );
+    }
+
+    #[test]
+    fn test_try_new_reporting() {
+        let (family_name, modified) =
+            PackageFamilyName::try_new_reporting("Microsoft.WindowsTerminal", MICROSOFT).unwrap();
+        assert!(!modified);
+        assert_eq!(
+            family_name.to_string(),
+            "Microsoft.WindowsTerminal_8wekyb3d8bbwe"
+        );
+
+        let (family_name, modified) = PackageFamilyName::try_new_reporting(
+            " Microsoft.WindowsTerminal\t\n".to_string(),
+            MICROSOFT,
+        )
+        .unwrap();
+        assert!(modified);
+        assert_eq!(family_name.package_name(), "Microsoft.WindowsTerminal");
+
+        assert_eq!(
+            PackageFamilyName::try_new_reporting(" ab ", MICROSOFT),
+            Err(PackageFamilyNameError::InvalidPackageName(
+                PackageNameError::InvalidLength
+            ))
+        );
+    }
+
+    #[test]
+    fn test_from_parts() {
+        let package_family_name =
+            PackageFamilyName::parse("Microsoft.WindowsTerminal_8wekyb3d8bbwe").unwrap();
+
+        let from_parts = PackageFamilyName::from_parts(
+            package_family_name.package_name(),
+            package_family_name.publisher_id().clone(),
+        );
+        assert_eq!(from_parts, package_family_name);
+        assert!(from_parts.is_name_borrowed());
+
+        let owned = PackageFamilyName::from_parts(
+            String::from("Microsoft.WindowsTerminal"),
+            PublisherId::new(MICROSOFT),
+        );
+        assert_eq!(owned.to_string(), "Microsoft.WindowsTerminal_8wekyb3d8bbwe");
+        assert!(!owned.is_name_borrowed());
+    }
+
+    #[test]
+    fn test_from_owned_parts() {
+        let mut package_name = String::with_capacity(32);
+        package_name.push_str("Microsoft.WindowsTerminal");
+        let pointer = package_name.as_ptr();
+
+        let package_family_name =
+            PackageFamilyName::from_owned_parts(package_name, PublisherId::new(MICROSOFT));
+
+        assert_eq!(package_family_name.package_name().as_ptr(), pointer);
+        assert_eq!(
+            package_family_name.to_string(),
+            "Microsoft.WindowsTerminal_8wekyb3d8bbwe"
+        );
+    }
+
+    #[test]
+    fn test_combined_validation_errors() {
+        fn validate(package_name: &str, publisher_id: &str) -> Result<(), PackageFamilyNameError> {
+            validate_package_name(package_name)?;
+            publisher_id.parse::<PublisherId>()?;
+            Ok(())
+        }
+
+        assert_eq!(validate("Microsoft.PowerShell", "8wekyb3d8bbwe"), Ok(()));
+        assert_eq!(
+            validate("ab", "8wekyb3d8bbwe"),
+            Err(PackageFamilyNameError::InvalidPackageName(
+                PackageNameError::InvalidLength
+            ))
+        );
+        assert_eq!(
+            validate("Microsoft.PowerShell", "8wekyb3d8bbw"),
+            Err(PackageFamilyNameError::PublisherId(
+                PublisherIdError::InvalidLength { actual: 12 }
+            ))
+        );
+        assert_eq!(
+            validate("Microsoft.PowerShell", "8wekyb3d8bbwl"),
+            Err(PackageFamilyNameError::PublisherId(
+                PublisherIdError::InvalidCharacters {
+                    character: 'l',
+                    index: 12
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn test_normalize() {
+        let mixed_case: PackageFamilyName =
+            "Microsoft.WindowsTerminal_8WEKYB3D8BBWE".parse().unwrap();
+        let uppercase: PackageFamilyName =
+            "MICROSOFT.WINDOWSTERMINAL_8wekyb3d8bbwe".parse().unwrap();
+
+        let normalized = mixed_case.normalize();
+        assert_eq!(
+            normalized.to_string(),
+            "microsoft.windowsterminal_8wekyb3d8bbwe"
+        );
+        assert_eq!(normalized.to_string(), uppercase.normalize().to_string());
+        assert_eq!(normalized, mixed_case);
+    }
+
+    #[cfg(feature = "defmt")]
+    #[test]
+    fn test_defmt_format() {
+        fn assert_format<T: defmt::Format>() {}
+
+        assert_format::<PackageFamilyName>();
+        assert_format::<PublisherId>();
+    }
+
+    #[test]
+    fn test_relation() {
+        let terminal = PackageFamilyName::new("Microsoft.WindowsTerminal", MICROSOFT);
+
+        assert_eq!(
+            terminal.relation(&"microsoft.windowsterminal_8WEKYB3D8BBWE".parse().unwrap()),
+            Relation::Same
+        );
+        assert_eq!(
+            terminal.relation(&PackageFamilyName::new(
+                "Microsoft.WindowsTerminal",
+                "CN=Contoso"
+            )),
+            Relation::SameNameDifferentPublisher
+        );
+        assert_eq!(
+            terminal.relation(&PackageFamilyName::new("Microsoft.PowerShell", MICROSOFT)),
+            Relation::Different
+        );
+    }
+
+    #[test]
+    fn test_compare() {
+        let terminal = PackageFamilyName::new("Microsoft.WindowsTerminal", MICROSOFT);
+
+        assert_eq!(
+            terminal.compare(&"MICROSOFT.WINDOWSTERMINAL_8wekyb3d8bbwe".parse().unwrap()),
+            Comparison {
+                name_equal: true,
+                publisher_equal: true,
+                equal: true,
+            }
+        );
+        assert_eq!(
+            terminal.compare(&PackageFamilyName::new(
+                "Microsoft.WindowsTerminal",
+                "CN=Contoso"
+            )),
+            Comparison {
+                name_equal: true,
+                publisher_equal: false,
+                equal: false,
+            }
+        );
+        assert_eq!(
+            terminal.compare(&PackageFamilyName::new("Microsoft.PowerShell", MICROSOFT)),
+            Comparison {
+                name_equal: false,
+                publisher_equal: true,
+                equal: false,
+            }
+        );
+        assert_eq!(
+            terminal.compare(&PackageFamilyName::new("Contoso.App", "CN=Contoso")),
+            Comparison {
+                name_equal: false,
+                publisher_equal: false,
+                equal: false,
+            }
+        );
+    }
+
+    #[test]
+    fn test_descending() {
+        let mut family_names = [
+            "Microsoft.PowerShell",
+            "contoso.app",
+            "Microsoft.WindowsTerminal",
+            "Zoom.Zoom",
+        ]
+        .map(|name| Descending(PackageFamilyName::new(name, MICROSOFT)));
+        family_names.sort();
+
+        assert!(family_names
+            .iter()
+            .map(|Descending(family_name)| family_name.package_name())
+            .eq([
+                "Zoom.Zoom",
+                "Microsoft.WindowsTerminal",
+                "Microsoft.PowerShell",
+                "contoso.app"
+            ]));
+    }
+
+    #[test]
+    fn test_clone_keeps_borrowed_name() {
+        let name = "Microsoft.WindowsTerminal";
+
+        let borrowed = PackageFamilyName::new(name, MICROSOFT);
+        let borrowed_clone = borrowed.clone();
+        assert!(matches!(borrowed_clone.package_name, Cow::Borrowed(_)));
+        assert_eq!(borrowed_clone.package_name().as_ptr(), name.as_ptr());
+
+        let owned = PackageFamilyName::new(name.to_string(), MICROSOFT);
+        let owned_clone = owned.clone();
+        assert!(matches!(owned_clone.package_name, Cow::Owned(_)));
+        assert_ne!(
+            owned_clone.package_name().as_ptr(),
+            owned.package_name().as_ptr()
+        );
+    }
+
+    #[test]
+    fn test_non_ascii_names_ignore_case() {
+        let lowercase = PackageFamilyName::new("Contoso.Café", MICROSOFT);
+        let uppercase = PackageFamilyName::new("CONTOSO.CAFÉ", MICROSOFT);
+
+        assert_eq!(lowercase, uppercase);
+        assert_eq!(lowercase, PackageFamilyName::new("CONTOSO.CAFé", MICROSOFT));
+        assert_eq!(
+            PackageFamilyName::new("Müller.App", MICROSOFT),
+            PackageFamilyName::new("MÜLLER.APP", MICROSOFT)
+        );
+        assert_ne!(
+            PackageFamilyName::new("Müller.App", MICROSOFT),
+            PackageFamilyName::new("Muller.App", MICROSOFT)
+        );
+        assert_eq!(lowercase.normalize().package_name(), "contoso.café");
+        assert_eq!(uppercase.normalize().package_name(), "contoso.café");
+    }
+
+    #[test]
+    fn test_non_ascii_names_ord() {
+        let lowercase = PackageFamilyName::new("Contoso.Café", MICROSOFT);
+        let uppercase = PackageFamilyName::new("CONTOSO.CAFÉ", MICROSOFT);
+
+        assert_ne!(lowercase.package_name(), uppercase.package_name());
+        assert_eq!(lowercase.cmp(&uppercase), Ordering::Equal);
+        assert_eq!(
+            lowercase.cmp(&PackageFamilyName::new("CONTOSO.CAFÉ", "CN=Contoso")),
+            lowercase
+                .publisher_id()
+                .cmp(&PublisherId::new("CN=Contoso"))
+        );
+        assert_eq!(
+            lowercase.cmp(&PackageFamilyName::new("Contoso.Cafe", MICROSOFT)),
+            Ordering::Greater
+        );
+
+        let mut family_names = [
+            PackageFamilyName::new("Zoom.Zoom", MICROSOFT),
+            uppercase,
+            PackageFamilyName::new("Contoso.Cafeteria", MICROSOFT),
+        ];
+        family_names.sort();
+        assert_eq!(names_with_prefix(&family_names, "contoso.café").len(), 1);
+        assert_eq!(names_with_prefix(&family_names, "CONTOSO.CAF").len(), 2);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_non_ascii_names_hash() {
+        use std::collections::HashSet;
+
+        let family_names = [
+            PackageFamilyName::new("Contoso.Café", MICROSOFT),
+            PackageFamilyName::new("CONTOSO.CAFÉ", MICROSOFT),
+            PackageFamilyName::new("contoso.cafÉ", MICROSOFT),
+        ];
+
+        assert_eq!(family_names.into_iter().collect::<HashSet<_>>().len(), 1);
+    }
+
+    #[test]
+    fn test_eq_case_sensitive() {
+        let package_family_name = PackageFamilyName::new("Contoso.App", MICROSOFT);
+
+        assert!(package_family_name
+            .eq_case_sensitive(&PackageFamilyName::new("Contoso.App", MICROSOFT)));
+        assert!(package_family_name
+            .eq_case_sensitive(&PackageFamilyName::parse("Contoso.App_8WEKYB3D8BBWE").unwrap()));
+        assert_eq!(
+            package_family_name,
+            PackageFamilyName::new("CONTOSO.APP", MICROSOFT)
+        );
+        assert!(!package_family_name
+            .eq_case_sensitive(&PackageFamilyName::new("CONTOSO.APP", MICROSOFT)));
+        assert!(!package_family_name
+            .eq_case_sensitive(&PackageFamilyName::new("Contoso.App", "CN=Contoso")));
+    }
+
+    #[cfg(feature = "unicode")]
+    #[test]
+    fn test_eq_unicode_ci() {
+        let lowercase = PackageFamilyName::new("Contoso.Café", MICROSOFT);
+
+        assert!(lowercase.eq_unicode_ci(&PackageFamilyName::new("CONTOSO.CAFÉ", MICROSOFT)));
+
+        let sharp_s = PackageFamilyName::new("Contoso.Straße", MICROSOFT);
+        let double_s = PackageFamilyName::new("CONTOSO.STRASSE", MICROSOFT);
+        assert_ne!(sharp_s, double_s);
+        assert!(sharp_s.eq_unicode_ci(&double_s));
+        assert!(!lowercase.eq_unicode_ci(&PackageFamilyName::new("CONTOSO.CAFÉ", "CN=Contoso")));
+    }
+
+    #[test]
+    fn test_into_owned() {
+        let family_names: Vec<PackageFamilyName<'static>> = {
+            let source = "Microsoft.WindowsTerminal_8wekyb3d8bbwe".to_string();
+            let family_name = PackageFamilyName::parse(&source).unwrap();
+            assert!(family_name.is_name_borrowed());
+            vec![family_name.into_owned()]
+        };
+
+        assert!(!family_names[0].is_name_borrowed());
+        assert_eq!(
+            family_names[0].to_string(),
+            "Microsoft.WindowsTerminal_8wekyb3d8bbwe"
+        );
+    }
+
+    #[test]
+    fn test_is_name_borrowed() {
+        assert!(PackageFamilyName::new("AppName", MICROSOFT).is_name_borrowed());
+        assert!(!PackageFamilyName::new("AppName".to_string(), MICROSOFT).is_name_borrowed());
+        assert!(!"AppName_zj75k085cmj1a"
+            .parse::<PackageFamilyName>()
+            .unwrap()
+            .is_name_borrowed());
+    }
+
+    #[test]
+    fn test_compare_long_names() {
+        let names = ["".to_string(), "a".repeat(50), "a".repeat(1000)];
+        let family_names = names
+            .iter()
+            .map(|name| PackageFamilyName::new(name.as_str(), MICROSOFT))
+            .collect::<Vec<_>>();
+
+        for (index, family_name) in family_names.iter().enumerate() {
+            let uppercase = names[index].to_ascii_uppercase();
+            assert_eq!(*family_name, PackageFamilyName::new(uppercase, MICROSOFT));
+
+            for (other_index, other) in family_names.iter().enumerate() {
+                assert_eq!(family_name == other, index == other_index);
+                assert_eq!(family_name.cmp(other), index.cmp(&other_index));
+            }
+        }
+
+        let shorter = PackageFamilyName::new("a".repeat(999), MICROSOFT);
+        assert_eq!(shorter.cmp(&family_names[2]), Ordering::Less);
+        assert_eq!(family_names[2].cmp(&shorter), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_display_name_titlecase() {
+        let package_family_name = PackageFamilyName::new("microsoft.windows.photos", MICROSOFT);
+
+        assert_eq!(
+            package_family_name.display_name_titlecase(),
+            "Microsoft.Windows.Photos"
+        );
+        assert_eq!(
+            package_family_name.package_name(),
+            "microsoft.windows.photos"
+        );
+        assert_eq!(
+            PackageFamilyName::new("Microsoft.WindowsTerminal", MICROSOFT).display_name_titlecase(),
+            "Microsoft.WindowsTerminal"
+        );
+    }
+
+    #[test]
+    fn test_short_display() {
+        let photos = PackageFamilyName::new("Microsoft.Windows.Photos", MICROSOFT);
+
+        assert_eq!(
+            photos.short_display(100),
+            "Microsoft.Windows.Photos_8wekyb3d8bbwe"
+        );
+        assert_eq!(photos.short_display(38), photos.to_string());
+        assert_eq!(photos.short_display(37), "M.W.Photos_8wekyb3d8bbwe");
+        assert_eq!(photos.short_display(16), "M.W.Photos_8wek…");
+        assert_eq!(photos.short_display(1), "…");
+        assert_eq!(photos.short_display(0), "");
+
+        let app = PackageFamilyName::new("AppName", "Publisher Software");
+        assert_eq!(app.short_display(21), "AppName_zj75k085cmj1a");
+        assert_eq!(app.short_display(12), "AppName_zj7…");
+    }
+
+    #[test]
+    fn test_escaped_display() {
+        let package_family_name = PackageFamilyName::new("App\tName\u{9b}", MICROSOFT);
+
+        assert_eq!(
+            package_family_name.escaped_display().to_string(),
+            r"App\x09Name\u{009b}_8wekyb3d8bbwe"
+        );
+        assert_eq!(
+            PackageFamilyName::new("AppName", MICROSOFT)
+                .escaped_display()
+                .to_string(),
+            "AppName_8wekyb3d8bbwe"
+        );
+    }
+
+    #[test]
+    fn test_is_valid_package_family_name() {
+        for valid in [
+            "Microsoft.WindowsTerminal_8wekyb3d8bbwe",
+            "microsoft.windowsterminal_8WEKYB3D8BBWE",
+            "My_App_8wekyb3d8bbwe",
+        ] {
+            assert!(is_valid_package_family_name(valid));
+            assert!(valid.parse::<PackageFamilyName>().is_ok());
+        }
+        for invalid in [
+            "",
+            "Microsoft.WindowsTerminal",
+            "_8wekyb3d8bbwe",
+            "Microsoft.WindowsTerminal_",
+            "Microsoft.WindowsTerminal_8wekyb3d8bbwl",
+        ] {
+            assert!(!is_valid_package_family_name(invalid));
+            assert!(invalid.parse::<PackageFamilyName>().is_err());
+        }
+    }
+
+    #[test]
+    fn test_validate_all() {
+        let names = [
+            "Microsoft.WindowsTerminal_8wekyb3d8bbwe",
+            "Microsoft.WindowsTerminal",
+            "AppName_zj75k085cmj1a",
+            "AppName_zj75k085cmj1",
+            "AppName_zj75k085cmj1u",
+        ];
+
+        assert_eq!(
+            validate_all(&names),
+            vec![
+                (1, PackageFamilyNameError::NoUnderscore),
+                (
+                    3,
+                    PackageFamilyNameError::PublisherId(PublisherIdError::InvalidLength {
+                        actual: 12
+                    })
+                ),
+                (
+                    4,
+                    PackageFamilyNameError::PublisherId(PublisherIdError::InvalidCharacters {
+                        character: 'u',
+                        index: 12
+                    })
+                ),
+            ]
+        );
+        assert!(validate_all(&names[..1]).is_empty());
+    }
+
+    #[test]
+    fn test_unique_publishers() {
+        let family_names = [
+            "Microsoft.WindowsTerminal_8wekyb3d8bbwe",
+            "AppName_zj75k085cmj1a",
+            "Microsoft.PowerShell_8WEKYB3D8BBWE",
+            "Contoso.App_h91ms92gdsmmt",
+            "OtherApp_zj75k085cmj1a",
+        ]
+        .map(|family_name| family_name.parse::<PackageFamilyName>().unwrap());
+
+        assert!(unique_publishers(family_names)
+            .map(|publisher_id| publisher_id.to_string())
+            .eq(["8wekyb3d8bbwe", "zj75k085cmj1a", "h91ms92gdsmmt"]));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_publisher_id_hash_set() {
+        use std::collections::HashSet;
+
+        let family_names = [
+            PackageFamilyName::new("Microsoft.WindowsTerminal", MICROSOFT),
+            PackageFamilyName::new("Contoso.App", "CN=Contoso"),
+            PackageFamilyName::new("Microsoft.PowerShell", MICROSOFT),
+        ];
+
+        let publisher_ids = family_names
+            .iter()
+            .map(|family_name| family_name.publisher_id().clone())
+            .collect::<HashSet<_>>();
+
+        assert_eq!(publisher_ids.len(), 2);
+        assert!(publisher_ids.contains(&"8WEKYB3D8BBWE".parse::<PublisherId>().unwrap()));
+        assert!(publisher_ids.contains(AsRef::<PublisherId>::as_ref(&family_names[1])));
+    }
+
+    #[test]
+    fn test_names_with_prefix() {
+        let mut family_names = [
+            "Microsoft.WindowsTerminal",
+            "Contoso.App",
+            "microsoft.windows.photos",
+            "Microsoft.PowerShell",
+            "Mozilla.Firefox",
+            "MicrosoftCorporationII.WindowsSubsystemForLinux",
+        ]
+        .into_iter()
+        .map(|name| PackageFamilyName::new(name, MICROSOFT))
+        .collect::<Vec<_>>();
+        family_names.sort();
+
+        let names = names_with_prefix(&family_names, "MICROSOFT.")
+            .iter()
+            .map(PackageFamilyName::package_name)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            names,
+            [
+                "Microsoft.PowerShell",
+                "microsoft.windows.photos",
+                "Microsoft.WindowsTerminal"
+            ]
+        );
+        assert_eq!(names_with_prefix(&family_names, "Micro").len(), 4);
+        assert!(names_with_prefix(&family_names, "Zoom").is_empty());
+    }
+
+    #[test]
+    fn test_static_lookup() {
+        let mut entries = [
+            (
+                PackageFamilyName::new("Microsoft.WindowsTerminal", MICROSOFT),
+                "Terminal",
+            ),
+            (
+                PackageFamilyName::new("Microsoft.PowerShell", MICROSOFT),
+                "PowerShell",
+            ),
+            (
+                PackageFamilyName::new("Microsoft.Windows.Photos", MICROSOFT),
+                "Photos",
+            ),
+        ];
+        entries.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let query = "microsoft.powershell_8WEKYB3D8BBWE".parse().unwrap();
+        assert_eq!(static_lookup(&entries, &query), Some(&"PowerShell"));
+
+        let query = "Microsoft.Paint_8wekyb3d8bbwe".parse().unwrap();
+        assert_eq!(static_lookup(&entries, &query), None);
+    }
+}
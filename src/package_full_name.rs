@@ -0,0 +1,430 @@
+use alloc::borrow::Cow;
+use alloc::string::ToString;
+use core::fmt;
+use core::str::FromStr;
+
+use thiserror::Error;
+
+use crate::package_family_name::eq_ignore_case;
+use crate::{
+    PackageFamilyName, PackageVersion, PackageVersionError, ProcessorArchitecture,
+    ProcessorArchitectureError, PublisherId, PublisherIdError,
+};
+
+/// The kind of a segment of a [`PackageFullName`], in the order that the segments appear.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SegmentKind {
+    Name,
+    Version,
+    Architecture,
+    /// The resource id, which is usually empty.
+    Resource,
+    PublisherId,
+}
+
+/// An MSIX Package Full Name, such as
+/// `Microsoft.Windows.Photos_2021.21090.10012.0_x64__8wekyb3d8bbwe`.
+///
+/// A full name identifies one specific package: the package name, version, processor
+/// architecture, resource id and Publisher Id, separated by underscores. The resource id is
+/// usually empty, which leaves two consecutive underscores.
+///
+/// Like [`PackageFamilyName`], comparison ignores the case of the package name, the resource id
+/// and the Publisher Id.
+#[derive(Clone, Debug)]
+pub struct PackageFullName<'ident> {
+    package_name: Cow<'ident, str>,
+    version: PackageVersion,
+    architecture: ProcessorArchitecture,
+    resource_id: Option<Cow<'ident, str>>,
+    publisher_id: PublisherId,
+}
+
+impl<'ident> PackageFullName<'ident> {
+    /// Returns the package name, the first segment.
+    #[inline]
+    pub fn package_name(&self) -> &str {
+        &self.package_name
+    }
+
+    /// Returns the version, the second segment.
+    #[inline]
+    pub const fn version(&self) -> PackageVersion {
+        self.version
+    }
+
+    /// Returns the processor architecture, the third segment.
+    #[inline]
+    pub const fn architecture(&self) -> ProcessorArchitecture {
+        self.architecture
+    }
+
+    /// Returns the resource id, the fourth segment, or `None` if it is empty.
+    #[inline]
+    pub fn resource_id(&self) -> Option<&str> {
+        self.resource_id.as_deref()
+    }
+
+    /// Returns the Publisher Id, the last segment.
+    #[inline]
+    pub const fn publisher_id(&self) -> &PublisherId {
+        &self.publisher_id
+    }
+
+    /// Returns the family name of the package, dropping the version, architecture and resource
+    /// id, like Windows' `PackageFamilyNameFromFullName`.
+    ///
+    /// ```
+    /// use package_family_name::PackageFullName;
+    ///
+    /// let full_name =
+    ///     PackageFullName::parse("Microsoft.Windows.Photos_2021.21090.10012.0_x64__8wekyb3d8bbwe")
+    ///         .unwrap();
+    ///
+    /// assert_eq!(full_name.family_name(), "Microsoft.Windows.Photos_8wekyb3d8bbwe");
+    /// ```
+    pub fn family_name(&self) -> PackageFamilyName<'_> {
+        PackageFamilyName::from_parts(&*self.package_name, self.publisher_id.clone())
+    }
+
+    /// Parses a Package Full Name, borrowing its segments from `s`.
+    #[inline]
+    pub fn parse(s: &'ident str) -> Result<Self, PackageFullNameError> {
+        Self::try_from(s)
+    }
+
+    /// Converts into a full name that owns all of its segments.
+    pub fn into_owned(self) -> PackageFullName<'static> {
+        PackageFullName {
+            package_name: Cow::Owned(self.package_name.into_owned()),
+            version: self.version,
+            architecture: self.architecture,
+            resource_id: self
+                .resource_id
+                .map(|resource_id| Cow::Owned(resource_id.into_owned())),
+            publisher_id: self.publisher_id,
+        }
+    }
+}
+
+impl fmt::Display for PackageFullName<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}_{}_{}_{}_{}",
+            self.package_name,
+            self.version,
+            self.architecture,
+            self.resource_id.as_deref().unwrap_or_default(),
+            self.publisher_id
+        )
+    }
+}
+
+/// Iterates over the five segments of the full name in order, labeled with their kind, so that
+/// they can be rendered uniformly.
+///
+/// The resource id is yielded as an empty string when there is none. The version is the only
+/// segment that isn't stored as text, so it is the only one that is formatted into an owned
+/// string.
+///
+/// ```
+/// use package_family_name::{PackageFullName, SegmentKind};
+///
+/// let full_name =
+///     PackageFullName::parse("Microsoft.Windows.Photos_2021.21090.10012.0_x64__8wekyb3d8bbwe")
+///         .unwrap();
+/// let segments: Vec<_> = full_name
+///     .into_iter()
+///     .map(|(kind, segment)| (kind, segment.into_owned()))
+///     .collect();
+///
+/// assert_eq!(
+///     segments,
+///     [
+///         (SegmentKind::Name, "Microsoft.Windows.Photos".to_owned()),
+///         (SegmentKind::Version, "2021.21090.10012.0".to_owned()),
+///         (SegmentKind::Architecture, "x64".to_owned()),
+///         (SegmentKind::Resource, String::new()),
+///         (SegmentKind::PublisherId, "8wekyb3d8bbwe".to_owned()),
+///     ]
+/// );
+/// ```
+impl<'a> IntoIterator for &'a PackageFullName<'_> {
+    type Item = (SegmentKind, Cow<'a, str>);
+    type IntoIter = core::array::IntoIter<Self::Item, 5>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        [
+            (SegmentKind::Name, Cow::Borrowed(self.package_name())),
+            (SegmentKind::Version, Cow::Owned(self.version.to_string())),
+            (
+                SegmentKind::Architecture,
+                Cow::Borrowed(self.architecture.as_str()),
+            ),
+            (
+                SegmentKind::Resource,
+                Cow::Borrowed(self.resource_id().unwrap_or_default()),
+            ),
+            (
+                SegmentKind::PublisherId,
+                Cow::Borrowed(self.publisher_id.as_str()),
+            ),
+        ]
+        .into_iter()
+    }
+}
+
+/// Converts a full name into its family name, the same as [`PackageFullName::family_name`].
+///
+/// ```
+/// use package_family_name::{PackageFamilyName, PackageFullName};
+///
+/// let full_name =
+///     PackageFullName::parse("Microsoft.Windows.Photos_2021.21090.10012.0_x64__8wekyb3d8bbwe")
+///         .unwrap();
+/// let family_name: PackageFamilyName = (&full_name).into();
+///
+/// assert_eq!(family_name, "Microsoft.Windows.Photos_8wekyb3d8bbwe");
+/// ```
+impl<'a> From<&'a PackageFullName<'_>> for PackageFamilyName<'a> {
+    #[inline]
+    fn from(full_name: &'a PackageFullName<'_>) -> Self {
+        full_name.family_name()
+    }
+}
+
+impl PartialEq for PackageFullName<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.publisher_id == other.publisher_id
+            && self.architecture == other.architecture
+            && self.version == other.version
+            && eq_ignore_case(&self.package_name, &other.package_name)
+            && match (self.resource_id(), other.resource_id()) {
+                (Some(resource_id), Some(other)) => eq_ignore_case(resource_id, other),
+                (resource_id, other) => resource_id == other,
+            }
+    }
+}
+
+impl Eq for PackageFullName<'_> {}
+
+/// The error returned when a Package Full Name can't be parsed.
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PackageFullNameError {
+    /// There aren't exactly five underscore-separated segments.
+    #[error("Package Full Name must have five underscore-separated segments: package name, version, architecture, resource id and Publisher Id")]
+    WrongSegmentCount,
+    /// The first segment is empty.
+    #[error("Package Full Name must start with a package name")]
+    EmptyPackageName,
+    /// The second segment isn't a four-part version.
+    #[error(transparent)]
+    Version(#[from] PackageVersionError),
+    /// The third segment isn't a processor architecture.
+    #[error(transparent)]
+    Architecture(#[from] ProcessorArchitectureError),
+    /// The last segment isn't a valid Publisher Id, as described by [`PublisherIdError`].
+    #[error(transparent)]
+    PublisherId(#[from] PublisherIdError),
+}
+
+impl FromStr for PackageFullName<'_> {
+    type Err = PackageFullNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        PackageFullName::try_from(s).map(PackageFullName::into_owned)
+    }
+}
+
+/// Parses a full name like [`FromStr`], but borrows its segments from `s` rather than
+/// allocating.
+impl<'ident> TryFrom<&'ident str> for PackageFullName<'ident> {
+    type Error = PackageFullNameError;
+
+    fn try_from(s: &'ident str) -> Result<Self, Self::Error> {
+        let mut segments = s.split('_');
+        let (
+            Some(package_name),
+            Some(version),
+            Some(architecture),
+            Some(resource_id),
+            Some(publisher_id),
+            None,
+        ) = (
+            segments.next(),
+            segments.next(),
+            segments.next(),
+            segments.next(),
+            segments.next(),
+            segments.next(),
+        )
+        else {
+            return Err(PackageFullNameError::WrongSegmentCount);
+        };
+
+        if package_name.is_empty() {
+            return Err(PackageFullNameError::EmptyPackageName);
+        }
+
+        Ok(Self {
+            package_name: Cow::Borrowed(package_name),
+            version: version.parse()?,
+            architecture: architecture.parse()?,
+            resource_id: Some(resource_id)
+                .filter(|resource_id| !resource_id.is_empty())
+                .map(Cow::Borrowed),
+            publisher_id: publisher_id.parse()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+    use alloc::vec::Vec;
+
+    use crate::{
+        PackageFamilyName, PackageFullName, PackageFullNameError, PackageVersion,
+        PackageVersionError, ProcessorArchitecture, ProcessorArchitectureError, PublisherIdError,
+        SegmentKind,
+    };
+
+    const PHOTOS: &str = "Microsoft.Windows.Photos_2021.21090.10012.0_x64__8wekyb3d8bbwe";
+    const LANGUAGE_PACK: &str =
+        "Microsoft.LanguageExperiencePackfr-FR_19041.1.2.0_neutral_split.language-fr_8wekyb3d8bbwe";
+
+    #[test]
+    fn test_parse_full_name() {
+        let full_name = PackageFullName::parse(PHOTOS).unwrap();
+
+        assert_eq!(full_name.package_name(), "Microsoft.Windows.Photos");
+        assert_eq!(
+            full_name.version(),
+            PackageVersion::new(2021, 21090, 10012, 0)
+        );
+        assert_eq!(full_name.architecture(), ProcessorArchitecture::X64);
+        assert_eq!(full_name.resource_id(), None);
+        assert_eq!(full_name.publisher_id(), "8wekyb3d8bbwe");
+        assert_eq!(full_name.to_string(), PHOTOS);
+    }
+
+    #[test]
+    fn test_parse_full_name_with_resource_id() {
+        let full_name = LANGUAGE_PACK.parse::<PackageFullName>().unwrap();
+
+        assert_eq!(full_name.architecture(), ProcessorArchitecture::Neutral);
+        assert_eq!(full_name.resource_id(), Some("split.language-fr"));
+        assert_eq!(full_name.to_string(), LANGUAGE_PACK);
+    }
+
+    #[test]
+    fn test_full_name_segments() {
+        let full_name = PackageFullName::parse(PHOTOS).unwrap();
+
+        assert_eq!(
+            full_name.into_iter().collect::<Vec<_>>(),
+            [
+                (SegmentKind::Name, "Microsoft.Windows.Photos".into()),
+                (SegmentKind::Version, "2021.21090.10012.0".into()),
+                (SegmentKind::Architecture, "x64".into()),
+                (SegmentKind::Resource, "".into()),
+                (SegmentKind::PublisherId, "8wekyb3d8bbwe".into()),
+            ]
+        );
+
+        let language_pack = PackageFullName::parse(LANGUAGE_PACK).unwrap();
+        let segments = language_pack
+            .into_iter()
+            .map(|(_, segment)| segment)
+            .collect::<Vec<_>>();
+        assert_eq!(segments.join("_"), LANGUAGE_PACK);
+    }
+
+    #[test]
+    fn test_full_name_family_name() {
+        let full_name = PackageFullName::parse(PHOTOS).unwrap();
+
+        assert_eq!(
+            full_name.family_name(),
+            PackageFamilyName::parse("Microsoft.Windows.Photos_8wekyb3d8bbwe").unwrap()
+        );
+        assert!(full_name.family_name().is_name_borrowed());
+    }
+
+    #[test]
+    fn test_family_name_from_full_name() {
+        let full_name = LANGUAGE_PACK.parse::<PackageFullName>().unwrap();
+
+        let family_name = PackageFamilyName::from(&full_name);
+        assert_eq!(family_name, full_name.family_name());
+        assert_eq!(
+            family_name.to_string(),
+            "Microsoft.LanguageExperiencePackfr-FR_8wekyb3d8bbwe"
+        );
+        assert!(family_name.is_name_borrowed());
+    }
+
+    #[test]
+    fn test_full_name_eq_ignores_case() {
+        assert_eq!(
+            PackageFullName::parse(PHOTOS).unwrap(),
+            PackageFullName::parse(
+                "MICROSOFT.WINDOWS.PHOTOS_2021.21090.10012.0_X64__8WEKYB3D8BBWE"
+            )
+            .unwrap()
+        );
+        assert_ne!(
+            PackageFullName::parse(PHOTOS).unwrap(),
+            PackageFullName::parse(
+                "Microsoft.Windows.Photos_2021.21090.10012.0_x86__8wekyb3d8bbwe"
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_full_name() {
+        for full_name in [
+            "",
+            "Microsoft.Windows.Photos_8wekyb3d8bbwe",
+            "Microsoft.Windows.Photos_2021.21090.10012.0_x64_8wekyb3d8bbwe",
+            "Microsoft_Windows.Photos_2021.21090.10012.0_x64__8wekyb3d8bbwe",
+        ] {
+            assert_eq!(
+                PackageFullName::parse(full_name),
+                Err(PackageFullNameError::WrongSegmentCount)
+            );
+        }
+        assert_eq!(
+            PackageFullName::parse("_2021.21090.10012.0_x64__8wekyb3d8bbwe"),
+            Err(PackageFullNameError::EmptyPackageName)
+        );
+        assert_eq!(
+            PackageFullName::parse("Microsoft.Windows.Photos__x64__8wekyb3d8bbwe"),
+            Err(PackageFullNameError::Version(
+                PackageVersionError::InvalidPart
+            ))
+        );
+        assert_eq!(
+            PackageFullName::parse("Microsoft.Windows.Photos_2021.21090_x64__8wekyb3d8bbwe"),
+            Err(PackageFullNameError::Version(
+                PackageVersionError::WrongPartCount
+            ))
+        );
+        assert_eq!(
+            PackageFullName::parse(
+                "Microsoft.Windows.Photos_2021.21090.10012.0_amd64__8wekyb3d8bbwe"
+            ),
+            Err(PackageFullNameError::Architecture(
+                ProcessorArchitectureError::UnknownName
+            ))
+        );
+        assert_eq!(
+            PackageFullName::parse("Microsoft.Windows.Photos_2021.21090.10012.0_x64__8wekyb3d8bbw"),
+            Err(PackageFullNameError::PublisherId(
+                PublisherIdError::InvalidLength { actual: 12 }
+            ))
+        );
+    }
+}
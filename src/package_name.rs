@@ -0,0 +1,252 @@
+#[cfg(feature = "alloc")]
+use alloc::borrow::{Cow, ToOwned};
+#[cfg(feature = "alloc")]
+use core::fmt;
+#[cfg(feature = "alloc")]
+use core::hash::{Hash, Hasher};
+#[cfg(feature = "alloc")]
+use core::str::FromStr;
+
+use thiserror::Error;
+
+/// The minimum length of an MSIX package name.
+pub const MIN_PACKAGE_NAME_LEN: usize = 3;
+
+/// The maximum length of an MSIX package name.
+pub const MAX_PACKAGE_NAME_LEN: usize = 50;
+
+/// Returns whether `name` is within the length limits of an MSIX package name, between
+/// [`MIN_PACKAGE_NAME_LEN`] and [`MAX_PACKAGE_NAME_LEN`] characters.
+///
+/// Package names are ASCII, so the length is counted in bytes. This is a `const fn` so that
+/// hardcoded names can be checked at compile time:
+///
+/// ```
+/// use package_family_name::validate_name_len;
+///
+/// const NAME: &str = "Microsoft.WindowsTerminal";
+/// const _: () = assert!(validate_name_len(NAME));
+/// ```
+///
+/// ```compile_fail
+/// use package_family_name::validate_name_len;
+///
+/// const NAME: &str = "Microsoft.WindowsTerminal.WithAVeryLongNameThatIsTooLong";
+/// const _: () = assert!(validate_name_len(NAME));
+/// ```
+pub const fn validate_name_len(name: &str) -> bool {
+    name.len() >= MIN_PACKAGE_NAME_LEN && name.len() <= MAX_PACKAGE_NAME_LEN
+}
+
+/// Names reserved by Windows that a package name can't be or start with, followed by a period.
+const RESERVED_NAMES: [&str; 22] = [
+    "CON", "PRN", "AUX", "NUL", "COM1", "COM2", "COM3", "COM4", "COM5", "COM6", "COM7", "COM8",
+    "COM9", "LPT1", "LPT2", "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9",
+];
+
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PackageNameError {
+    #[error(
+        "Package name must be between {MIN_PACKAGE_NAME_LEN} and {MAX_PACKAGE_NAME_LEN} characters long"
+    )]
+    InvalidLength,
+    #[error(
+        "Package name must only contain ASCII letters, digits, periods and dashes, but contains {character:?}"
+    )]
+    InvalidCharacter { character: char },
+    #[error("Package name must not start or end with a period")]
+    LeadingOrTrailingPeriod,
+    #[error("Package name must not be a reserved device name such as CON or COM1")]
+    ReservedName,
+}
+
+/// Checks that `name` is a valid MSIX package name.
+///
+/// A package name is between [`MIN_PACKAGE_NAME_LEN`] and [`MAX_PACKAGE_NAME_LEN`] characters of
+/// `A-Z`, `a-z`, `0-9`, `.` and `-`. It can't start or end with a period, and its first
+/// period-separated segment can't be a reserved device name such as `CON`, `NUL` or `COM1`.
+pub fn validate_package_name(name: &str) -> Result<(), PackageNameError> {
+    if !validate_name_len(name) {
+        return Err(PackageNameError::InvalidLength);
+    }
+
+    if let Some(character) = name
+        .chars()
+        .find(|&character| !(character.is_ascii_alphanumeric() || matches!(character, '.' | '-')))
+    {
+        return Err(PackageNameError::InvalidCharacter { character });
+    }
+
+    if name.starts_with('.') || name.ends_with('.') {
+        return Err(PackageNameError::LeadingOrTrailingPeriod);
+    }
+
+    let first_segment = name.split('.').next().unwrap_or(name);
+    if RESERVED_NAMES
+        .iter()
+        .any(|reserved| first_segment.eq_ignore_ascii_case(reserved))
+    {
+        return Err(PackageNameError::ReservedName);
+    }
+
+    Ok(())
+}
+
+/// A validated MSIX package name, such as `Microsoft.WindowsTerminal`.
+///
+/// Like [`PackageFamilyName`](crate::PackageFamilyName), comparison and hashing are ASCII
+/// case-insensitive. A `PackageName` converts into the name accepted by
+/// [`PackageFamilyName::new`](crate::PackageFamilyName::new), so a family name can be built from
+/// a name that is known to be valid.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug)]
+pub struct PackageName<'name>(Cow<'name, str>);
+
+#[cfg(feature = "alloc")]
+impl<'name> PackageName<'name> {
+    /// Validates `name` with [`validate_package_name`].
+    pub fn new(name: impl Into<Cow<'name, str>>) -> Result<Self, PackageNameError> {
+        let name = name.into();
+        validate_package_name(&name)?;
+        Ok(Self(name))
+    }
+
+    /// Returns the package name as a string slice.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Display for PackageName<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl AsRef<str> for PackageName<'_> {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'name> From<PackageName<'name>> for Cow<'name, str> {
+    #[inline]
+    fn from(package_name: PackageName<'name>) -> Self {
+        package_name.0
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl PartialEq for PackageName<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.eq_ignore_ascii_case(&other.0)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Eq for PackageName<'_> {}
+
+#[cfg(feature = "alloc")]
+impl Hash for PackageName<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for byte in self.0.bytes() {
+            state.write_u8(byte.to_ascii_lowercase());
+        }
+        state.write_u8(0xff);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl FromStr for PackageName<'_> {
+    type Err = PackageNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        validate_package_name(s)?;
+        Ok(Self(Cow::Owned(s.to_owned())))
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use alloc::string::ToString;
+
+    use crate::{
+        validate_name_len, validate_package_name, PackageFamilyName, PackageName, PackageNameError,
+    };
+
+    #[test]
+    fn test_validate_name_len() {
+        assert!(!validate_name_len(""));
+        assert!(!validate_name_len("ab"));
+        assert!(validate_name_len("abc"));
+        assert!(validate_name_len(&"a".repeat(50)));
+        assert!(!validate_name_len(&"a".repeat(51)));
+    }
+
+    #[test]
+    fn test_validate_package_name() {
+        assert_eq!(validate_package_name("Microsoft.PowerShell"), Ok(()));
+        assert_eq!(
+            validate_package_name("ab"),
+            Err(PackageNameError::InvalidLength)
+        );
+        assert_eq!(
+            validate_package_name("Contoso App"),
+            Err(PackageNameError::InvalidCharacter { character: ' ' })
+        );
+        assert_eq!(
+            validate_package_name("Contoso/App"),
+            Err(PackageNameError::InvalidCharacter { character: '/' })
+        );
+        assert_eq!(
+            validate_package_name("Contoso.Café"),
+            Err(PackageNameError::InvalidCharacter { character: 'é' })
+        );
+        assert_eq!(
+            validate_package_name(".Contoso.App"),
+            Err(PackageNameError::LeadingOrTrailingPeriod)
+        );
+        assert_eq!(
+            validate_package_name("Contoso.App."),
+            Err(PackageNameError::LeadingOrTrailingPeriod)
+        );
+        assert_eq!(
+            validate_package_name("con"),
+            Err(PackageNameError::ReservedName)
+        );
+        assert_eq!(
+            validate_package_name("COM1.App"),
+            Err(PackageNameError::ReservedName)
+        );
+        assert_eq!(validate_package_name("Console.App"), Ok(()));
+        assert_eq!(validate_package_name("App.CON"), Ok(()));
+    }
+
+    #[test]
+    fn test_package_name() {
+        let package_name = PackageName::new("Microsoft.WindowsTerminal").unwrap();
+
+        assert_eq!(package_name.as_str(), "Microsoft.WindowsTerminal");
+        assert_eq!(
+            "MICROSOFT.WINDOWSTERMINAL".parse::<PackageName>(),
+            Ok(package_name.clone())
+        );
+        assert_eq!(
+            PackageName::new("My App"),
+            Err(PackageNameError::InvalidCharacter { character: ' ' })
+        );
+        assert_eq!(
+            PackageFamilyName::new(
+                package_name,
+                "CN=Microsoft Corporation, O=Microsoft Corporation, L=Redmond, S=Washington, C=US"
+            )
+            .to_string(),
+            "Microsoft.WindowsTerminal_8wekyb3d8bbwe"
+        );
+    }
+}
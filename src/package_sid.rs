@@ -0,0 +1,140 @@
+#[cfg(feature = "alloc")]
+use alloc::{format, string::String};
+use core::fmt;
+
+use sha2::{Digest, Sha256};
+
+/// The number of sub-authorities hashed from the app container name.
+const HASH_SUB_AUTHORITY_COUNT: usize = 7;
+
+/// The AppContainer security identifier of a package, such as
+/// `S-1-15-2-3624051433-2125758914-1423191267-1740899205-1073925389-3782572162-737981194`.
+///
+/// Windows derives it from the SHA-256 digest of the lowercased, UTF-16LE encoded package family
+/// name: the first 28 bytes of the digest are split into seven little-endian `u32`
+/// sub-authorities, which follow the app package authority `S-1-15-2`. The last 4 bytes of the
+/// digest are not used.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PackageSid([u32; HASH_SUB_AUTHORITY_COUNT]);
+
+impl PackageSid {
+    /// Calculates the SID of an app container name, which for a package is its family name.
+    ///
+    /// The name is lowercased before it is hashed, so names that only differ by case have the
+    /// same SID.
+    ///
+    /// ```
+    /// use package_family_name::PackageSid;
+    ///
+    /// let package_sid =
+    ///     PackageSid::from_app_container_name("Microsoft.MicrosoftEdge_8wekyb3d8bbwe");
+    ///
+    /// assert_eq!(
+    ///     package_sid.to_string(),
+    ///     "S-1-15-2-3624051433-2125758914-1423191267-1740899205-1073925389-3782572162-737981194"
+    /// );
+    /// ```
+    pub fn from_app_container_name(name: impl AsRef<str>) -> Self {
+        Self::from_chars(name.as_ref().chars().flat_map(char::to_lowercase))
+    }
+
+    /// Calculates the SID from the characters of an already lowercased app container name.
+    pub(crate) fn from_chars(name: impl Iterator<Item = char>) -> Self {
+        let mut buffer = [0; 2];
+        let digest = name
+            .fold(Sha256::new(), |hasher, character| {
+                character
+                    .encode_utf16(&mut buffer)
+                    .iter()
+                    .fold(hasher, |hasher, code_unit| {
+                        hasher.chain_update(code_unit.to_le_bytes())
+                    })
+            })
+            .finalize();
+
+        let mut sub_authorities = [0; HASH_SUB_AUTHORITY_COUNT];
+        for (sub_authority, bytes) in sub_authorities.iter_mut().zip(digest.chunks_exact(4)) {
+            *sub_authority =
+                u32::from_le_bytes(bytes.try_into().unwrap_or_else(|_| unreachable!()));
+        }
+
+        Self(sub_authorities)
+    }
+
+    /// Returns the seven sub-authorities that were hashed from the app container name, without
+    /// the leading `2` of the app package authority.
+    #[inline]
+    pub const fn sub_authorities(&self) -> [u32; HASH_SUB_AUTHORITY_COUNT] {
+        self.0
+    }
+
+    /// Returns the object manager directory that holds the named objects, such as events and
+    /// mutexes, that the package creates in the given logon session.
+    ///
+    /// ```
+    /// use package_family_name::PackageSid;
+    ///
+    /// let package_sid =
+    ///     PackageSid::from_app_container_name("Microsoft.MicrosoftEdge_8wekyb3d8bbwe");
+    ///
+    /// assert_eq!(
+    ///     package_sid.named_objects_directory(1),
+    ///     format!("\\Sessions\\1\\AppContainerNamedObjects\\{package_sid}")
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn named_objects_directory(&self, session_id: u32) -> String {
+        format!("\\Sessions\\{session_id}\\AppContainerNamedObjects\\{self}")
+    }
+}
+
+impl fmt::Display for PackageSid {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("S-1-15-2")?;
+        self.0
+            .iter()
+            .try_for_each(|sub_authority| write!(f, "-{sub_authority}"))
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use alloc::string::ToString;
+
+    use crate::{PackageFamilyName, PackageSid};
+
+    const MICROSOFT: &str =
+        "CN=Microsoft Corporation, O=Microsoft Corporation, L=Redmond, S=Washington, C=US";
+
+    #[test]
+    fn test_package_sid() {
+        let package_sid =
+            PackageFamilyName::new("Microsoft.MicrosoftEdge", MICROSOFT).to_package_sid();
+
+        assert_eq!(
+            package_sid.to_string(),
+            "S-1-15-2-3624051433-2125758914-1423191267-1740899205-1073925389-3782572162-737981194"
+        );
+        assert_eq!(package_sid.sub_authorities()[0], 3_624_051_433);
+        assert_eq!(
+            package_sid.named_objects_directory(0),
+            "\\Sessions\\0\\AppContainerNamedObjects\\S-1-15-2-3624051433-2125758914-1423191267-\
+            1740899205-1073925389-3782572162-737981194"
+        );
+    }
+
+    #[test]
+    fn test_package_sid_ignores_case() {
+        assert_eq!(
+            PackageSid::from_app_container_name("MICROSOFT.MICROSOFTEDGE_8WEKYB3D8BBWE"),
+            PackageSid::from_app_container_name("microsoft.microsoftedge_8wekyb3d8bbwe")
+        );
+        assert_eq!(
+            "Microsoft.MicrosoftEdge_8WEKYB3D8BBWE"
+                .parse::<PackageFamilyName>()
+                .unwrap()
+                .to_package_sid(),
+            PackageSid::from_app_container_name("microsoft.microsoftedge_8wekyb3d8bbwe")
+        );
+    }
+}
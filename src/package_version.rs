@@ -0,0 +1,245 @@
+use core::fmt;
+use core::str::FromStr;
+
+use thiserror::Error;
+
+/// An MSIX package version, such as `2021.21090.10012.0`.
+///
+/// Versions are ordered numerically, part by part, so `1.10.0.0` is newer than `1.9.0.0`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PackageVersion {
+    pub major: u16,
+    pub minor: u16,
+    pub build: u16,
+    pub revision: u16,
+}
+
+impl PackageVersion {
+    #[inline]
+    pub const fn new(major: u16, minor: u16, build: u16, revision: u16) -> Self {
+        Self {
+            major,
+            minor,
+            build,
+            revision,
+        }
+    }
+
+    /// Parses a version leniently, for importing data from tools that don't respect the limits of
+    /// a package version.
+    ///
+    /// **This is lossy**: a part above `65535` is clamped to [`u16::MAX`], so different inputs can
+    /// parse to the same version. Otherwise the version must be valid, with exactly four whole
+    /// number parts. Use [`str::parse`] for the strict parsing that Windows does.
+    ///
+    /// ```
+    /// use package_family_name::PackageVersion;
+    ///
+    /// assert_eq!(
+    ///     PackageVersion::parse_saturating("1.70000.3.0"),
+    ///     Ok(PackageVersion::new(1, u16::MAX, 3, 0))
+    /// );
+    /// assert!("1.70000.3.0".parse::<PackageVersion>().is_err());
+    /// ```
+    pub fn parse_saturating(s: &str) -> Result<Self, PackageVersionError> {
+        let mut parts = [0; 4];
+        let mut segments = s.split('.');
+
+        for part in &mut parts {
+            let segment = segments.next().ok_or(PackageVersionError::WrongPartCount)?;
+
+            if segment.is_empty() || !segment.bytes().all(|byte| byte.is_ascii_digit()) {
+                return Err(PackageVersionError::InvalidPart);
+            }
+            *part = segment.bytes().fold(0_u16, |part, digit| {
+                part.saturating_mul(10)
+                    .saturating_add(u16::from(digit - b'0'))
+            });
+        }
+
+        if segments.next().is_some() {
+            return Err(PackageVersionError::WrongPartCount);
+        }
+
+        let [major, minor, build, revision] = parts;
+        Ok(Self::new(major, minor, build, revision))
+    }
+
+    /// Packs the version into a single integer the way Windows does in `PACKAGE_VERSION`, with
+    /// the major version in the highest 16 bits and the revision in the lowest.
+    ///
+    /// Packed versions order the same way as the versions they were packed from.
+    ///
+    /// ```
+    /// use package_family_name::PackageVersion;
+    ///
+    /// assert_eq!(PackageVersion::new(1, 2, 3, 4).to_u64(), 0x0001_0002_0003_0004);
+    /// ```
+    #[inline]
+    pub const fn to_u64(self) -> u64 {
+        (self.major as u64) << 48
+            | (self.minor as u64) << 32
+            | (self.build as u64) << 16
+            | self.revision as u64
+    }
+
+    /// Unpacks a version from the integer form of [`to_u64`](Self::to_u64).
+    #[inline]
+    pub const fn from_u64(version: u64) -> Self {
+        Self::new(
+            (version >> 48) as u16,
+            (version >> 32) as u16,
+            (version >> 16) as u16,
+            version as u16,
+        )
+    }
+}
+
+impl fmt::Display for PackageVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}.{}.{}.{}",
+            self.major, self.minor, self.build, self.revision
+        )
+    }
+}
+
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PackageVersionError {
+    #[error("Package version must have exactly four parts separated by periods")]
+    WrongPartCount,
+    #[error("Package version parts must be whole numbers from 0 to {}", u16::MAX)]
+    InvalidPart,
+}
+
+impl FromStr for PackageVersion {
+    type Err = PackageVersionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = [0; 4];
+        let mut segments = s.split('.');
+
+        for part in &mut parts {
+            let segment = segments.next().ok_or(PackageVersionError::WrongPartCount)?;
+
+            // `u16::from_str` accepts a leading `+`, which isn't valid in a version
+            if segment.is_empty() || !segment.bytes().all(|byte| byte.is_ascii_digit()) {
+                return Err(PackageVersionError::InvalidPart);
+            }
+            *part = segment
+                .parse()
+                .map_err(|_| PackageVersionError::InvalidPart)?;
+        }
+
+        if segments.next().is_some() {
+            return Err(PackageVersionError::WrongPartCount);
+        }
+
+        let [major, minor, build, revision] = parts;
+        Ok(Self::new(major, minor, build, revision))
+    }
+}
+
+impl From<PackageVersion> for u64 {
+    #[inline]
+    fn from(version: PackageVersion) -> Self {
+        version.to_u64()
+    }
+}
+
+impl From<u64> for PackageVersion {
+    #[inline]
+    fn from(version: u64) -> Self {
+        Self::from_u64(version)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{PackageVersion, PackageVersionError};
+
+    #[test]
+    fn test_parse_package_version() {
+        assert_eq!(
+            "2021.21090.10012.0".parse(),
+            Ok(PackageVersion::new(2021, 21090, 10012, 0))
+        );
+        assert_eq!(
+            "65535.0.0.65535".parse(),
+            Ok(PackageVersion::new(u16::MAX, 0, 0, u16::MAX))
+        );
+
+        for version in ["1.2.3", "1.2.3.4.5", "1"] {
+            assert_eq!(
+                version.parse::<PackageVersion>(),
+                Err(PackageVersionError::WrongPartCount)
+            );
+        }
+        for version in [
+            "",
+            "1.2.3.",
+            "1..3.4",
+            "1.2.3.65536",
+            "1.+2.3.4",
+            "1.2.3.-4",
+            "a.b.c.d",
+        ] {
+            assert_eq!(
+                version.parse::<PackageVersion>(),
+                Err(PackageVersionError::InvalidPart)
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_saturating() {
+        assert_eq!(
+            PackageVersion::parse_saturating("2021.21090.10012.0"),
+            "2021.21090.10012.0".parse()
+        );
+        assert_eq!(
+            PackageVersion::parse_saturating("65536.99999999999999999999.1.65535"),
+            Ok(PackageVersion::new(u16::MAX, u16::MAX, 1, u16::MAX))
+        );
+        assert_eq!(
+            "65536.0.0.0".parse::<PackageVersion>(),
+            Err(PackageVersionError::InvalidPart)
+        );
+
+        for version in ["1.2", "70000", "1.2.3.4.5"] {
+            assert_eq!(
+                PackageVersion::parse_saturating(version),
+                Err(PackageVersionError::WrongPartCount)
+            );
+        }
+        for version in ["", "1..3.4", "1.2.3.", "1.+2.3.4", "a.b.c.d"] {
+            assert_eq!(
+                PackageVersion::parse_saturating(version),
+                Err(PackageVersionError::InvalidPart)
+            );
+        }
+    }
+
+    #[test]
+    fn test_package_version_order() {
+        let older = "1.9.0.0".parse::<PackageVersion>().unwrap();
+        let newer = "1.10.0.0".parse::<PackageVersion>().unwrap();
+
+        assert!(older < newer);
+        assert!(older.to_u64() < newer.to_u64());
+        assert!(PackageVersion::new(0, 0, 0, u16::MAX) < PackageVersion::new(0, 0, 1, 0));
+    }
+
+    #[test]
+    fn test_package_version_u64_round_trip() {
+        let version = PackageVersion::new(2021, 21090, 10012, 0);
+
+        assert_eq!(PackageVersion::from_u64(version.to_u64()), version);
+        assert_eq!(
+            PackageVersion::from(u64::MAX),
+            PackageVersion::new(u16::MAX, u16::MAX, u16::MAX, u16::MAX)
+        );
+        assert_eq!(u64::from(PackageVersion::default()), 0);
+    }
+}
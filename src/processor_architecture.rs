@@ -0,0 +1,142 @@
+use core::fmt;
+use core::str::FromStr;
+
+use thiserror::Error;
+
+/// The processor architecture that a package targets, the third segment of a package full name.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ProcessorArchitecture {
+    X86,
+    X64,
+    Arm,
+    Arm64,
+    /// The package runs on any architecture.
+    Neutral,
+}
+
+impl ProcessorArchitecture {
+    /// Returns the lowercase spelling that Windows uses in package full names, such as `x64`.
+    #[inline]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::X86 => "x86",
+            Self::X64 => "x64",
+            Self::Arm => "arm",
+            Self::Arm64 => "arm64",
+            Self::Neutral => "neutral",
+        }
+    }
+
+    /// Returns the `PROCESSOR_ARCHITECTURE_*` constant of the architecture, as used by
+    /// `PACKAGE_ID` and `SYSTEM_INFO`.
+    #[inline]
+    pub const fn to_u16(self) -> u16 {
+        match self {
+            Self::X86 => 0,
+            Self::Arm => 5,
+            Self::X64 => 9,
+            Self::Neutral => 11,
+            Self::Arm64 => 12,
+        }
+    }
+}
+
+impl fmt::Display for ProcessorArchitecture {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProcessorArchitectureError {
+    #[error("Processor architecture must be one of x86, x64, arm, arm64 or neutral")]
+    UnknownName,
+    #[error("{0} is not a PROCESSOR_ARCHITECTURE_* value of a package architecture")]
+    UnknownValue(u16),
+}
+
+/// Parses the spelling that Windows uses in package full names, ignoring ASCII case.
+impl FromStr for ProcessorArchitecture {
+    type Err = ProcessorArchitectureError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        [Self::X86, Self::X64, Self::Arm, Self::Arm64, Self::Neutral]
+            .into_iter()
+            .find(|architecture| architecture.as_str().eq_ignore_ascii_case(s))
+            .ok_or(ProcessorArchitectureError::UnknownName)
+    }
+}
+
+/// Converts a `PROCESSOR_ARCHITECTURE_*` constant, such as the `ProcessorArchitecture` field of
+/// a `PACKAGE_ID`.
+impl TryFrom<u16> for ProcessorArchitecture {
+    type Error = ProcessorArchitectureError;
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::X86),
+            5 => Ok(Self::Arm),
+            9 => Ok(Self::X64),
+            11 => Ok(Self::Neutral),
+            12 => Ok(Self::Arm64),
+            _ => Err(ProcessorArchitectureError::UnknownValue(value)),
+        }
+    }
+}
+
+impl From<ProcessorArchitecture> for u16 {
+    #[inline]
+    fn from(architecture: ProcessorArchitecture) -> Self {
+        architecture.to_u16()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ProcessorArchitecture, ProcessorArchitectureError};
+
+    const ARCHITECTURES: [ProcessorArchitecture; 5] = [
+        ProcessorArchitecture::X86,
+        ProcessorArchitecture::X64,
+        ProcessorArchitecture::Arm,
+        ProcessorArchitecture::Arm64,
+        ProcessorArchitecture::Neutral,
+    ];
+
+    #[test]
+    fn test_processor_architecture_from_str() {
+        for architecture in ARCHITECTURES {
+            assert_eq!(architecture.as_str().parse(), Ok(architecture));
+        }
+        assert_eq!("X64".parse(), Ok(ProcessorArchitecture::X64));
+        assert_eq!("Neutral".parse(), Ok(ProcessorArchitecture::Neutral));
+        assert_eq!(
+            "amd64".parse::<ProcessorArchitecture>(),
+            Err(ProcessorArchitectureError::UnknownName)
+        );
+        assert_eq!(
+            "".parse::<ProcessorArchitecture>(),
+            Err(ProcessorArchitectureError::UnknownName)
+        );
+    }
+
+    #[test]
+    fn test_processor_architecture_u16() {
+        for architecture in ARCHITECTURES {
+            assert_eq!(
+                ProcessorArchitecture::try_from(u16::from(architecture)),
+                Ok(architecture)
+            );
+        }
+        assert_eq!(ProcessorArchitecture::X64.to_u16(), 9);
+        assert_eq!(ProcessorArchitecture::Arm64.to_u16(), 12);
+        assert_eq!(
+            ProcessorArchitecture::try_from(6),
+            Err(ProcessorArchitectureError::UnknownValue(6))
+        );
+        assert_eq!(
+            ProcessorArchitecture::try_from(0xffff),
+            Err(ProcessorArchitectureError::UnknownValue(0xffff))
+        );
+    }
+}
@@ -0,0 +1,453 @@
+//! Helpers for working with identity publisher distinguished names.
+
+#[cfg(feature = "alloc")]
+use alloc::borrow::Cow;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Builds an identity publisher distinguished name from its attributes.
+///
+/// Windows hashes the publisher exactly as written, so the order of the attributes changes the
+/// [`PublisherId`](crate::PublisherId). The attributes are written in the order they are added,
+/// which must match the order of the publisher in the package manifest.
+///
+/// ```
+/// use package_family_name::publisher::Publisher;
+///
+/// let publisher = Publisher::new()
+///     .common_name("Microsoft Corporation")
+///     .organization("Microsoft Corporation")
+///     .locality("Redmond")
+///     .state("Washington")
+///     .country("US");
+///
+/// assert_eq!(
+///     publisher.to_dn_string(),
+///     "CN=Microsoft Corporation, O=Microsoft Corporation, L=Redmond, S=Washington, C=US"
+/// );
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Publisher {
+    attributes: Vec<(String, String)>,
+}
+
+#[cfg(feature = "alloc")]
+impl Publisher {
+    /// Creates a publisher without any attributes.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            attributes: Vec::new(),
+        }
+    }
+
+    /// Adds a common name (`CN`) attribute.
+    #[inline]
+    pub fn common_name(self, value: impl Into<String>) -> Self {
+        self.attribute("CN", value)
+    }
+
+    /// Adds an organization (`O`) attribute.
+    #[inline]
+    pub fn organization(self, value: impl Into<String>) -> Self {
+        self.attribute("O", value)
+    }
+
+    /// Adds an organizational unit (`OU`) attribute.
+    #[inline]
+    pub fn organizational_unit(self, value: impl Into<String>) -> Self {
+        self.attribute("OU", value)
+    }
+
+    /// Adds a locality (`L`) attribute.
+    #[inline]
+    pub fn locality(self, value: impl Into<String>) -> Self {
+        self.attribute("L", value)
+    }
+
+    /// Adds a state or province (`S`) attribute.
+    #[inline]
+    pub fn state(self, value: impl Into<String>) -> Self {
+        self.attribute("S", value)
+    }
+
+    /// Adds a country (`C`) attribute.
+    #[inline]
+    pub fn country(self, value: impl Into<String>) -> Self {
+        self.attribute("C", value)
+    }
+
+    /// Adds an attribute with any key, such as `SERIALNUMBER` or a dotted OID like
+    /// `OID.2.5.4.15`.
+    pub fn attribute(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.attributes.push((key.into(), value.into()));
+        self
+    }
+
+    /// Returns the attributes as `(key, value)` pairs, in the order they were added.
+    pub fn attributes(&self) -> impl ExactSizeIterator<Item = (&str, &str)> {
+        self.attributes
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+    }
+
+    /// Formats the publisher as a distinguished name, with the attributes separated by `, `.
+    ///
+    /// Values that would otherwise be ambiguous, such as ones containing a comma, are quoted the
+    /// same way as Windows.
+    pub fn to_dn_string(&self) -> String {
+        let mut dn = String::new();
+
+        for (index, (key, value)) in self.attributes().enumerate() {
+            if index > 0 {
+                dn.push_str(", ");
+            }
+            dn.push_str(key);
+            dn.push('=');
+            push_value(&mut dn, value);
+        }
+
+        dn
+    }
+}
+
+/// Splits a distinguished name into its `(key, value)` attributes, in the order they are written.
+///
+/// Values can be quoted, with `""` or `\"` for a quote inside them, or use the backslash escapes
+/// of [RFC 4514](https://www.rfc-editor.org/rfc/rfc4514#section-2.4), such as `\,` for a comma or
+/// `\2C` for a hex-encoded byte. Attributes of a multi-valued RDN, joined by `+`, are returned as
+/// separate attributes. An attribute without a `=` is returned with an empty value.
+///
+/// Values are only copied if they had to be unescaped. Parsing doesn't change how a publisher is
+/// hashed: it is for displaying or diffing publishers whose Publisher Ids differ.
+///
+/// ```
+/// use package_family_name::publisher::parse_dn;
+///
+/// assert_eq!(
+///     parse_dn(r#"CN="Contoso, Ltd", O=Contoso\, Ltd, C=US"#),
+///     [
+///         ("CN".into(), "Contoso, Ltd".into()),
+///         ("O".into(), "Contoso, Ltd".into()),
+///         ("C".into(), "US".into()),
+///     ]
+/// );
+/// ```
+#[cfg(feature = "alloc")]
+pub fn parse_dn(dn: &str) -> Vec<(Cow<'_, str>, Cow<'_, str>)> {
+    let mut attributes = Vec::new();
+    let mut rest = dn.trim_start();
+
+    while !rest.is_empty() {
+        let key_end = rest.find(['=', ',', '+', ';']).unwrap_or(rest.len());
+        let key = rest[..key_end].trim();
+        rest = &rest[key_end..];
+
+        let value = match rest.strip_prefix('=') {
+            Some(value) => {
+                let (value, remainder) = parse_value(value.trim_start());
+                rest = remainder;
+                value
+            }
+            None => Cow::Borrowed(""),
+        };
+        attributes.push((Cow::Borrowed(key), value));
+
+        rest = rest.trim_start();
+        rest = rest
+            .strip_prefix([',', '+', ';'])
+            .unwrap_or(rest)
+            .trim_start();
+    }
+
+    attributes
+}
+
+/// Parses an attribute value from the start of `s`, returning it along with the rest of `s`
+/// after it.
+#[cfg(feature = "alloc")]
+fn parse_value(s: &str) -> (Cow<'_, str>, &str) {
+    if let Some(quoted) = s.strip_prefix('"') {
+        let mut value = String::new();
+        let mut characters = quoted.char_indices();
+
+        while let Some((index, character)) = characters.next() {
+            match character {
+                '"' if quoted[index + 1..].starts_with('"') => {
+                    characters.next();
+                    value.push('"');
+                }
+                '"' => return (Cow::Owned(value), &quoted[index + 1..]),
+                '\\' => value.extend(characters.next().map(|(_, escaped)| escaped)),
+                character => value.push(character),
+            }
+        }
+
+        // An unterminated quote runs to the end of the distinguished name
+        return (Cow::Owned(value), "");
+    }
+
+    let mut end = s.len();
+    let mut escaped = false;
+    for (index, character) in s.char_indices() {
+        match character {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            ',' | '+' | ';' => {
+                end = index;
+                break;
+            }
+            _ => {}
+        }
+    }
+
+    let (mut raw, rest) = s.split_at(end);
+    // Trailing spaces are only part of the value if they are escaped
+    while raw.ends_with(' ') && !raw.ends_with("\\ ") {
+        raw = &raw[..raw.len() - 1];
+    }
+
+    if raw.contains('\\') {
+        (Cow::Owned(unescape(raw)), rest)
+    } else {
+        (Cow::Borrowed(raw), rest)
+    }
+}
+
+/// Resolves the backslash escapes of an unquoted value, where a backslash is followed by either
+/// the character it escapes or two hex digits of a UTF-8 byte.
+#[cfg(feature = "alloc")]
+fn unescape(raw: &str) -> String {
+    let mut bytes = Vec::with_capacity(raw.len());
+    let mut rest = raw;
+
+    while let Some(index) = rest.find('\\') {
+        bytes.extend_from_slice(&rest.as_bytes()[..index]);
+        rest = &rest[index + 1..];
+
+        let hex_byte = rest
+            .get(..2)
+            .filter(|hex| hex.bytes().all(|byte| byte.is_ascii_hexdigit()))
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match hex_byte {
+            Some(byte) => {
+                bytes.push(byte);
+                rest = &rest[2..];
+            }
+            None => {
+                let escaped_len = rest.chars().next().map_or(0, char::len_utf8);
+                bytes.extend_from_slice(&rest.as_bytes()[..escaped_len]);
+                rest = &rest[escaped_len..];
+            }
+        }
+    }
+    bytes.extend_from_slice(rest.as_bytes());
+
+    String::from_utf8(bytes)
+        .unwrap_or_else(|error| String::from_utf8_lossy(error.as_bytes()).into_owned())
+}
+
+/// Appends an attribute value to a distinguished name, quoting it if it has leading or trailing
+/// spaces or contains a special character. Quotes within a quoted value are doubled.
+#[cfg(feature = "alloc")]
+pub(crate) fn push_value(dn: &mut String, value: &str) {
+    let needs_quotes = value.starts_with(' ')
+        || value.ends_with(' ')
+        || value.contains([',', '+', '=', '"', '\r', '\n', '<', '>', '#', ';']);
+
+    if needs_quotes {
+        dn.push('"');
+        for character in value.chars() {
+            if character == '"' {
+                dn.push('"');
+            }
+            dn.push(character);
+        }
+        dn.push('"');
+    } else {
+        dn.push_str(value);
+    }
+}
+
+/// Returns whether two identity publishers are hashed from the same input and are therefore
+/// guaranteed to produce the same [`PublisherId`](crate::PublisherId).
+///
+/// UTF-16 encoding is lossless, so this is exact string equality and is cheaper than hashing
+/// both publishers. A `false` result means the inputs differ, which almost always means the
+/// Publisher Ids differ too, but a collision in the truncated hash can't be ruled out.
+#[inline]
+pub fn same_hash_input(a: &str, b: &str) -> bool {
+    a == b
+}
+
+/// Returns the number of bytes that an identity publisher occupies once UTF-16LE encoded, which
+/// is exactly the input that is hashed into its [`PublisherId`](crate::PublisherId).
+///
+/// Characters outside the Basic Multilingual Plane are encoded as surrogate pairs and take 4
+/// bytes, while all others take 2.
+#[inline]
+pub fn utf16_byte_len(identity_publisher: &str) -> usize {
+    identity_publisher
+        .chars()
+        .map(char::len_utf16)
+        .sum::<usize>()
+        * size_of::<u16>()
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "alloc")]
+    use alloc::borrow::Cow;
+
+    #[cfg(feature = "alloc")]
+    use crate::publisher::{parse_dn, Publisher};
+    use crate::publisher::{same_hash_input, utf16_byte_len};
+    #[cfg(feature = "alloc")]
+    use crate::PublisherId;
+
+    #[test]
+    fn test_same_hash_input() {
+        let publisher =
+            "CN=Microsoft Corporation, O=Microsoft Corporation, L=Redmond, S=Washington, C=US";
+
+        assert!(same_hash_input(publisher, publisher));
+        assert!(!same_hash_input(
+            publisher,
+            "CN=Microsoft Corporation, O=Microsoft Corporation, L=Redmond, S=Washington, C=us"
+        ));
+        assert!(!same_hash_input("CN=Contoso", "CN=Contoso "));
+    }
+
+    #[test]
+    fn test_utf16_byte_len() {
+        assert_eq!(utf16_byte_len(""), 0);
+        assert_eq!(utf16_byte_len("CN=Contoso"), 20);
+        assert_eq!(utf16_byte_len("CN=Café"), 14);
+        assert_eq!(utf16_byte_len("CN=\u{1F600}"), 10);
+
+        let publisher = "CN=Contoso \u{1F600}, O=Zürich";
+        assert_eq!(
+            utf16_byte_len(publisher),
+            publisher.encode_utf16().count() * 2
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_publisher() {
+        let publisher = Publisher::new()
+            .common_name("Contoso Ltd")
+            .organization("Contoso Ltd")
+            .attribute("STREET", "1 Main St")
+            .locality("Redmond")
+            .state("Washington")
+            .country("US")
+            .attribute("OID.1.3.6.1.4.1.311.60.2.1.3", "US")
+            .attribute("SERIALNUMBER", "600413485")
+            .attribute("OID.2.5.4.15", "Private Organization");
+
+        assert_eq!(
+            publisher.to_dn_string(),
+            "CN=Contoso Ltd, O=Contoso Ltd, STREET=1 Main St, L=Redmond, S=Washington, C=US, \
+            OID.1.3.6.1.4.1.311.60.2.1.3=US, SERIALNUMBER=600413485, OID.2.5.4.15=Private Organization"
+        );
+        assert_eq!(PublisherId::from_publisher(&publisher), "0wgvnbzg7vzny");
+        assert_eq!(publisher.attributes().len(), 9);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_publisher_order_changes_publisher_id() {
+        let contoso = Publisher::new().common_name("Contoso").country("US");
+        let reordered = Publisher::new().country("US").common_name("Contoso");
+
+        assert_eq!(reordered.to_dn_string(), "C=US, CN=Contoso");
+        assert_ne!(
+            PublisherId::from_publisher(&contoso),
+            PublisherId::from_publisher(&reordered)
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_publisher_quotes_values() {
+        assert_eq!(Publisher::new().to_dn_string(), "");
+        assert_eq!(
+            Publisher::new()
+                .common_name("Contoso, Ltd")
+                .organization("The \"Contoso\" Company")
+                .organizational_unit(" Apps ")
+                .to_dn_string(),
+            r#"CN="Contoso, Ltd", O="The ""Contoso"" Company", OU=" Apps ""#
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_parse_dn() {
+        let attributes = parse_dn(
+            "CN=Microsoft Corporation, O=Microsoft Corporation, L=Redmond, S=Washington, C=US",
+        );
+
+        assert_eq!(
+            attributes,
+            [
+                ("CN", "Microsoft Corporation"),
+                ("O", "Microsoft Corporation"),
+                ("L", "Redmond"),
+                ("S", "Washington"),
+                ("C", "US"),
+            ]
+            .map(|(key, value)| (Cow::Borrowed(key), Cow::Borrowed(value)))
+        );
+        assert!(attributes
+            .iter()
+            .all(|(_, value)| matches!(value, Cow::Borrowed(_))));
+        assert!(parse_dn("").is_empty());
+        assert!(parse_dn("  ").is_empty());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_parse_dn_quoted_and_escaped() {
+        assert_eq!(
+            parse_dn(r#"CN="Contoso ""Apps"", Ltd" + OU=\"Dev\"\, Test, O=Caf\C3\A9\ , C=U\+S"#),
+            [
+                ("CN", "Contoso \"Apps\", Ltd"),
+                ("OU", "\"Dev\", Test"),
+                ("O", "Café "),
+                ("C", "U+S"),
+            ]
+            .map(|(key, value)| (Cow::Borrowed(key), Cow::Borrowed(value)))
+        );
+        assert_eq!(
+            parse_dn(r#"CN=" Contoso "#),
+            [(Cow::Borrowed("CN"), Cow::Borrowed(" Contoso "))]
+        );
+        assert_eq!(
+            parse_dn("CN=Contoso, Test, O="),
+            [("CN", "Contoso"), ("Test", ""), ("O", "")]
+                .map(|(key, value)| (Cow::Borrowed(key), Cow::Borrowed(value)))
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_parse_dn_round_trips_publisher() {
+        let publisher = Publisher::new()
+            .common_name("Contoso, Ltd")
+            .organization("The \"Contoso\" Company")
+            .organizational_unit(" Apps ")
+            .country("US");
+
+        let reparsed = parse_dn(&publisher.to_dn_string())
+            .into_iter()
+            .fold(Publisher::new(), |reparsed, (key, value)| {
+                reparsed.attribute(key, value)
+            });
+        assert_eq!(reparsed, publisher);
+    }
+}
@@ -0,0 +1,1143 @@
+//! The Publisher Id, the hashed half of a Package Family Name.
+
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::str::FromStr;
+
+use heapless::String;
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+#[cfg(feature = "alloc")]
+use crate::publisher::Publisher;
+
+/// The 13-character Crockford Base32 hash of an identity publisher.
+///
+/// Publisher Ids are always stored in canonical lowercase, including ones parsed from uppercase,
+/// so comparison, ordering and hashing ignore case like Windows does, and agree with
+/// [`Display`](fmt::Display) and [`Borrow<str>`](Borrow).
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct PublisherId([u8; Self::LENGTH]);
+
+impl PublisherId {
+    /// The number of characters in a Publisher Id.
+    pub const LENGTH: usize = 13;
+
+    /// The Publisher Id of an empty identity publisher, `werc8gmrzge18`.
+    ///
+    /// An empty publisher is almost always a mistake, such as reading the wrong manifest field,
+    /// so tools can compare against this value to flag it.
+    ///
+    /// ```
+    /// use package_family_name::PublisherId;
+    ///
+    /// assert_eq!(PublisherId::new(""), PublisherId::EMPTY_PUBLISHER_ID);
+    /// assert_eq!(PublisherId::EMPTY_PUBLISHER_ID.as_str(), "werc8gmrzge18");
+    /// ```
+    pub const EMPTY_PUBLISHER_ID: Self =
+        Self::from_hash_bytes([0xe3, 0xb0, 0xc4, 0x42, 0x98, 0xfc, 0x1c, 0x14]);
+
+    /// Calculates the Publisher Id of an identity publisher, such as
+    /// `CN=Microsoft Corporation, O=Microsoft Corporation, L=Redmond, S=Washington, C=US`.
+    pub fn new(identity_publisher: impl AsRef<str>) -> Self {
+        Self::new_with_digest(identity_publisher).0
+    }
+
+    /// Calculates the Publisher Id of an identity publisher along with the full SHA-256 digest
+    /// that it was derived from, in a single hashing pass.
+    ///
+    /// Only the first 8 bytes of the digest feed into the Publisher Id.
+    pub fn new_with_digest(identity_publisher: impl AsRef<str>) -> (Self, [u8; 32]) {
+        let publisher_sha_256: [u8; 32] = hash::<Sha256>(identity_publisher.as_ref()).into();
+
+        let truncated_hash = publisher_sha_256
+            .first_chunk()
+            .unwrap_or_else(|| unreachable!());
+
+        (Self::encode(*truncated_hash), publisher_sha_256)
+    }
+
+    /// Calculates the Publisher Id of a publisher built with [`Publisher`], by hashing its
+    /// [distinguished name](Publisher::to_dn_string).
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn from_publisher(publisher: &Publisher) -> Self {
+        Self::new(publisher.to_dn_string())
+    }
+
+    /// Calculates the Publisher Id of an identity publisher that is already UTF-16 encoded, such
+    /// as one returned by a Windows API, by hashing its code units as they are.
+    ///
+    /// Unlike [`new`](Self::new), this accepts unpaired surrogates, which can't be represented in
+    /// a `str` but are hashed by Windows all the same.
+    ///
+    /// ```
+    /// use package_family_name::PublisherId;
+    ///
+    /// let publisher = "CN=Contoso".encode_utf16().collect::<Vec<_>>();
+    ///
+    /// assert_eq!(PublisherId::from_utf16(&publisher), PublisherId::new("CN=Contoso"));
+    /// ```
+    pub fn from_utf16(identity_publisher: &[u16]) -> Self {
+        let digest = identity_publisher
+            .iter()
+            .fold(Sha256::new(), |hasher, code_unit| {
+                hasher.chain_update(code_unit.to_le_bytes())
+            })
+            .finalize();
+
+        Self::encode(*digest.first_chunk().unwrap_or_else(|| unreachable!()))
+    }
+
+    /// Calculates the Publisher Ids of many identity publishers, reusing one SHA-256 hasher by
+    /// resetting it between publishers instead of creating a new one for each.
+    ///
+    /// This yields the same Publisher Ids as calling [`new`](Self::new) on each publisher.
+    ///
+    /// ```
+    /// use package_family_name::PublisherId;
+    ///
+    /// let publisher_ids = PublisherId::from_publishers(["CN=Contoso", "CN=Fabrikam"]);
+    ///
+    /// assert!(publisher_ids.eq([PublisherId::new("CN=Contoso"), PublisherId::new("CN=Fabrikam")]));
+    /// ```
+    pub fn from_publishers<I, S>(identity_publishers: I) -> impl Iterator<Item = Self>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut hasher = Sha256::new();
+
+        identity_publishers
+            .into_iter()
+            .map(move |identity_publisher| {
+                for code_unit in identity_publisher.as_ref().encode_utf16() {
+                    hasher.update(code_unit.to_le_bytes());
+                }
+
+                let truncated_hash = *hasher
+                    .finalize_reset()
+                    .first_chunk()
+                    .unwrap_or_else(|| unreachable!());
+
+                Self::encode(truncated_hash)
+            })
+    }
+
+    /// Runs the Publisher Id pipeline with a hash algorithm other than SHA-256, returning the
+    /// Crockford Base32 encoding of the first 8 bytes of its digest.
+    ///
+    /// **This is non-standard** and only meant for research, such as comparing how the id would
+    /// look with SHA-512. Windows always uses SHA-256, which is what [`new`](Self::new) uses.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the digest of `D` is shorter than 8 bytes.
+    #[cfg(feature = "alloc")]
+    pub fn new_with_digest_algo<D: Digest>(
+        identity_publisher: impl AsRef<str>,
+    ) -> alloc::string::String {
+        let digest = hash::<D>(identity_publisher.as_ref());
+        assert!(
+            digest.len() >= 8,
+            "a Publisher Id needs an 8-byte digest, but the digest is only {} bytes",
+            digest.len()
+        );
+
+        let mut truncated_hash = [0; 8];
+        truncated_hash.copy_from_slice(&digest[..8]);
+        Self::encode(truncated_hash).into()
+    }
+
+    /// Calculates a Publisher Id from the lowercased identity publisher.
+    ///
+    /// **This is non-standard**: Windows hashes the publisher exactly as written, so
+    /// `CN=microsoft` and `CN=Microsoft` have different Publisher Ids, and the result of this
+    /// function won't match any installed package unless its publisher was already lowercase. It
+    /// is only meant for matching publishers across data sets where the casing has drifted.
+    #[cfg(feature = "alloc")]
+    pub fn new_case_insensitive(identity_publisher: impl AsRef<str>) -> Self {
+        Self::new(identity_publisher.as_ref().to_lowercase())
+    }
+
+    /// Creates a Publisher Id from a SHA-256 digest that has already been calculated, such as one
+    /// of the UTF-16LE encoded identity publisher.
+    ///
+    /// Only the first 8 bytes of the digest are used.
+    pub fn from_digest(digest: impl AsRef<[u8]>) -> Result<Self, DigestTooShortError> {
+        let digest = digest.as_ref();
+
+        digest
+            .first_chunk()
+            .copied()
+            .map(Self::encode)
+            .ok_or(DigestTooShortError { len: digest.len() })
+    }
+
+    /// Parses a Publisher Id, the same as [`str::parse`].
+    ///
+    /// ```
+    /// use package_family_name::PublisherId;
+    ///
+    /// let publisher_id = PublisherId::parse("8WEKYB3D8BBWE").unwrap();
+    ///
+    /// assert_eq!(publisher_id, "8wekyb3d8bbwe");
+    /// ```
+    #[inline]
+    pub fn parse(s: &str) -> Result<Self, PublisherIdError> {
+        s.parse()
+    }
+
+    /// Creates a Publisher Id from the first 8 bytes of the SHA-256 digest of an identity
+    /// publisher, without hashing anything.
+    ///
+    /// This is the inverse of [`hash_bytes`](Self::hash_bytes). Since it is a `const fn`, it can
+    /// build the Publisher Ids of well-known publishers at compile time:
+    ///
+    /// ```
+    /// use package_family_name::PublisherId;
+    ///
+    /// const MICROSOFT: PublisherId =
+    ///     PublisherId::from_hash_bytes([0x47, 0x1d, 0x3f, 0x2c, 0x6d, 0x42, 0xd7, 0xc7]);
+    ///
+    /// assert_eq!(MICROSOFT.as_str(), "8wekyb3d8bbwe");
+    /// ```
+    #[inline]
+    pub const fn from_hash_bytes(bytes: [u8; 8]) -> Self {
+        Self::encode(bytes)
+    }
+
+    /// Crockford Base32 encodes 8 bytes of a hash into a Publisher Id, without allocating.
+    const fn encode(truncated_hash: [u8; 8]) -> Self {
+        Self(encode_crockford(truncated_hash))
+    }
+
+    /// Returns the 32 characters that can appear in a Publisher Id: the Crockford Base32 alphabet
+    /// of `0-9` and `a-z` without `i`, `l`, `o` and `u`.
+    ///
+    /// The last character is narrower, as [`valid_last_chars`](Self::valid_last_chars)
+    /// describes.
+    ///
+    /// Publisher Ids are written in lowercase, but the uppercase forms of these characters are
+    /// also accepted when parsing.
+    #[inline]
+    pub const fn valid_next_chars() -> &'static [char] {
+        &CROCKFORD_ALPHABET
+    }
+
+    /// Returns the 16 characters that can be the last character of a Publisher Id.
+    ///
+    /// The last character also holds a padding bit that is always zero, so it can only be one of
+    /// the characters with an even value: `0`, `2`, `4`, `6`, `8`, `a`, `c`, `e`, `g`, `j`, `m`,
+    /// `p`, `r`, `t`, `w` or `y`. Like [`valid_next_chars`](Self::valid_next_chars), their
+    /// uppercase forms are also accepted when parsing.
+    #[inline]
+    pub const fn valid_last_chars() -> &'static [char] {
+        &CROCKFORD_LAST_CHARACTERS
+    }
+
+    /// Returns the Publisher Id as a string slice.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        // Every byte is from the Crockford Base32 alphabet, so it is always ASCII
+        core::str::from_utf8(&self.0).unwrap_or_else(|_| unreachable!())
+    }
+
+    /// Converts the Publisher Id into a fixed-capacity string.
+    ///
+    /// The string is always full: its length is [`LENGTH`](Self::LENGTH).
+    #[inline]
+    pub fn into_heapless(self) -> String<{ Self::LENGTH }> {
+        String::try_from(self.as_str()).unwrap_or_else(|_| unreachable!())
+    }
+
+    /// Decodes the Publisher Id back into the first 8 bytes of the SHA-256 hash it was encoded
+    /// from, for cross-checking a Publisher Id byte for byte.
+    ///
+    /// Parsing rejects a last character whose padding bit is set, so decoding is lossless:
+    /// [`from_hash_bytes`](Self::from_hash_bytes) turns the bytes back into the same Publisher Id.
+    /// This never fails and doesn't allocate.
+    ///
+    /// ```
+    /// use package_family_name::PublisherId;
+    ///
+    /// let publisher_id = PublisherId::new("Publisher Software");
+    /// let (_, digest) = PublisherId::new_with_digest("Publisher Software");
+    ///
+    /// assert_eq!(publisher_id.hash_bytes(), digest[..8]);
+    /// ```
+    #[inline]
+    pub const fn hash_bytes(&self) -> [u8; 8] {
+        decode_crockford(&self.0)
+    }
+
+    /// Returns whether two Publisher Ids are equal including their case.
+    #[deprecated(note = "Publisher Ids are always lowercase; use ==")]
+    #[inline]
+    pub fn eq_case_sensitive(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+
+    /// Returns whether the Publisher Id starts with `prefix`.
+    ///
+    /// Unlike equality with a `str`, this is case-sensitive, so only a lowercase prefix can match.
+    #[inline]
+    pub fn starts_with(&self, prefix: &str) -> bool {
+        self.as_str().starts_with(prefix)
+    }
+
+    /// Returns whether the Publisher Id ends with `suffix`.
+    ///
+    /// Unlike equality, this compares the raw string and is case-sensitive.
+    #[inline]
+    pub fn ends_with(&self, suffix: &str) -> bool {
+        self.as_str().ends_with(suffix)
+    }
+
+    /// Returns whether the Publisher Id contains `needle`.
+    ///
+    /// Unlike equality, this compares the raw string and is case-sensitive.
+    #[inline]
+    pub fn contains(&self, needle: &str) -> bool {
+        self.as_str().contains(needle)
+    }
+
+    /// Returns an iterator over the characters of the Publisher Id, which are always lowercase.
+    #[inline]
+    pub fn chars(&self) -> core::str::Chars<'_> {
+        self.as_str().chars()
+    }
+}
+
+impl fmt::Debug for PublisherId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("PublisherId").field(&self.as_str()).finish()
+    }
+}
+
+impl fmt::Display for PublisherId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Formats the 8 hash bytes that the Publisher Id encodes as 16 lowercase hex digits.
+///
+/// Two Publisher Ids are equal exactly when their hex is, as
+/// [`hash_bytes`](PublisherId::hash_bytes) describes.
+impl fmt::LowerHex for PublisherId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            f.write_str("0x")?;
+        }
+        self.hash_bytes()
+            .iter()
+            .try_for_each(|byte| write!(f, "{byte:02x}"))
+    }
+}
+
+/// Formats the 8 hash bytes that the Publisher Id encodes as 16 uppercase hex digits.
+impl fmt::UpperHex for PublisherId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            f.write_str("0x")?;
+        }
+        self.hash_bytes()
+            .iter()
+            .try_for_each(|byte| write!(f, "{byte:02X}"))
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for PublisherId {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "{=str}", self.as_str());
+    }
+}
+
+/// Compares against a string ignoring ASCII case, without parsing it, so a string that isn't a
+/// valid Publisher Id is unequal rather than an error.
+impl PartialEq<str> for PublisherId {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str().eq_ignore_ascii_case(other)
+    }
+}
+
+impl PartialEq<&str> for PublisherId {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+impl PartialOrd<str> for PublisherId {
+    fn partial_cmp(&self, other: &str) -> Option<Ordering> {
+        Some(cmp_ignore_ascii_case(self.as_str(), other))
+    }
+}
+
+impl PartialOrd<&str> for PublisherId {
+    #[inline]
+    fn partial_cmp(&self, other: &&str) -> Option<Ordering> {
+        self.partial_cmp(*other)
+    }
+}
+
+/// Hashes the same as the [`Borrow<str>`](Borrow) form, so that a `HashSet<PublisherId>` can be
+/// queried with a `&str`.
+impl Hash for PublisherId {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+/// Borrows the canonical lowercase form, so a lowercase `&str` can look up Publisher Ids in maps
+/// and sets.
+impl Borrow<str> for PublisherId {
+    #[inline]
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for PublisherId {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+/// ```
+/// use package_family_name::PublisherId;
+///
+/// let publisher_id: String = PublisherId::new("Publisher Software").into();
+///
+/// assert_eq!(publisher_id, "zj75k085cmj1a");
+/// ```
+#[cfg(feature = "alloc")]
+impl From<PublisherId> for alloc::string::String {
+    #[inline]
+    fn from(publisher_id: PublisherId) -> Self {
+        Self::from(publisher_id.as_str())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<&PublisherId> for alloc::string::String {
+    #[inline]
+    fn from(publisher_id: &PublisherId) -> Self {
+        Self::from(publisher_id.as_str())
+    }
+}
+
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PublisherIdError {
+    #[error("Publisher Id length was {actual}, expected {}", PublisherId::LENGTH)]
+    InvalidLength {
+        /// The length of the input in bytes.
+        actual: usize,
+    },
+    #[error("Publisher Id must only contain Crockford Base32 characters (0-9, a-z excluding i, l, o and u), but found {character:?} at index {index}")]
+    InvalidCharacters { character: char, index: usize },
+    /// The last character has an odd Crockford Base32 value, so the padding bit after the 64 hash
+    /// bits is set. No hash encodes to such a Publisher Id.
+    #[error("Publisher Id must end with one of 0, 2, 4, 6, 8, a, c, e, g, j, m, p, r, t, w or y, as its last bit is padding, but ended with {character:?}")]
+    InvalidLastCharacter { character: char },
+}
+
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("Digest must be at least 8 bytes long, but was {len} bytes")]
+pub struct DigestTooShortError {
+    pub len: usize,
+}
+
+impl FromStr for PublisherId {
+    type Err = PublisherIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // The length is checked before the characters so that it takes priority as the error, and
+        // checking it as a fixed-size array lets the character scan be unrolled into one pass
+        let bytes: &[u8; Self::LENGTH] = s
+            .as_bytes()
+            .try_into()
+            .map_err(|_| PublisherIdError::InvalidLength { actual: s.len() })?;
+
+        if let Some(index) = invalid_byte_index(bytes) {
+            // Every byte before `index` is ASCII, so it is the start of a character
+            let character = s[index..].chars().next().unwrap_or_else(|| unreachable!());
+            return Err(PublisherIdError::InvalidCharacters { character, index });
+        }
+
+        if !has_zero_padding_bit(bytes) {
+            return Err(PublisherIdError::InvalidLastCharacter {
+                character: char::from(bytes[Self::LENGTH - 1]),
+            });
+        }
+
+        // Uppercase input is lowercased so that the stored form is always canonical
+        let mut bytes = *bytes;
+        bytes.make_ascii_lowercase();
+        Ok(Self(bytes))
+    }
+}
+
+const CROCKFORD_ALPHABET: [char; 32] = [
+    '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'j',
+    'k', 'm', 'n', 'p', 'q', 'r', 's', 't', 'v', 'w', 'x', 'y', 'z',
+];
+
+/// The characters of [`CROCKFORD_ALPHABET`] with an even value, leaving the padding bit zero.
+const CROCKFORD_LAST_CHARACTERS: [char; 16] = [
+    '0', '2', '4', '6', '8', 'a', 'c', 'e', 'g', 'j', 'm', 'p', 'r', 't', 'w', 'y',
+];
+
+/// Crockford Base32 encodes 8 bytes into a stack buffer, producing the same output as fast32's
+/// `CROCKFORD_LOWER.encode` without an intermediate `String`.
+const fn encode_crockford(bytes: [u8; 8]) -> [u8; PublisherId::LENGTH] {
+    // The 13 characters hold 65 bits: the 64 bits of the hash followed by a padding bit
+    let bits = (u64::from_be_bytes(bytes) as u128) << 1;
+
+    let mut encoded = [0; PublisherId::LENGTH];
+    let mut index = 0;
+    while index < PublisherId::LENGTH {
+        let shift = 5 * (PublisherId::LENGTH - 1 - index);
+        encoded[index] = CROCKFORD_ALPHABET[((bits >> shift) & 0x1f) as usize] as u8;
+        index += 1;
+    }
+
+    encoded
+}
+
+/// Decodes 13 Crockford Base32 characters back into the 8 bytes that
+/// [`encode_crockford`] encoded them from, dropping the padding bit.
+const fn decode_crockford(encoded: &[u8; PublisherId::LENGTH]) -> [u8; 8] {
+    let mut bits = 0_u128;
+    let mut index = 0;
+    while index < PublisherId::LENGTH {
+        let Some(value) = crockford_value(encoded[index]) else {
+            unreachable!()
+        };
+        bits = bits << 5 | value as u128;
+        index += 1;
+    }
+
+    ((bits >> 1) as u64).to_be_bytes()
+}
+
+/// Hashes the UTF-16LE encoding of an identity publisher.
+fn hash<D: Digest>(identity_publisher: &str) -> sha2::digest::Output<D> {
+    identity_publisher
+        .encode_utf16()
+        .flat_map(u16::to_le_bytes)
+        .fold(D::new(), |buf, byte| buf.chain_update([byte]))
+        .finalize()
+}
+
+/// Returns the number of Crockford Base32 characters that `byte_count` bytes encode to, without
+/// padding.
+///
+/// Each character holds 5 bits, so this is the number of bits rounded up to a multiple of 5. A
+/// Publisher Id is the 8-byte case: 64 bits need 13 characters.
+#[inline]
+pub const fn encoded_len(byte_count: usize) -> usize {
+    (byte_count * u8::BITS as usize).div_ceil(5)
+}
+
+/// Calculates the full SHA-256 digest of the UTF-16LE encoding of an identity publisher, as a
+/// [`sha2`] output that can be passed straight to other RustCrypto APIs.
+///
+/// This is the step below [`PublisherId::new`]: only the first 8 bytes of the digest form the
+/// Publisher Id, and the remaining 24 are discarded.
+pub fn publisher_digest(identity_publisher: &str) -> sha2::digest::Output<Sha256> {
+    hash::<Sha256>(identity_publisher)
+}
+
+/// Calculates the full SHA-256 digest of the UTF-16LE encoding of an identity publisher, like
+/// [`publisher_digest`], as a plain byte array for logging or comparing against other tools.
+///
+/// ```
+/// use package_family_name::{publisher_sha256, PublisherId};
+///
+/// let digest = publisher_sha256(
+///     "CN=Microsoft Corporation, O=Microsoft Corporation, L=Redmond, S=Washington, C=US",
+/// );
+///
+/// assert_eq!(
+///     PublisherId::from_digest(&digest[..8]).unwrap().as_str(),
+///     "8wekyb3d8bbwe"
+/// );
+/// ```
+#[inline]
+pub fn publisher_sha256(identity_publisher: impl AsRef<str>) -> [u8; 32] {
+    publisher_digest(identity_publisher.as_ref()).into()
+}
+
+/// Searches for a publisher, made by appending an incrementing number to `base_publisher`, whose
+/// Publisher Id starts with `desired_prefix`, ignoring case.
+///
+/// This is a brute force search of up to `max_iters` publishers. Each character of the prefix
+/// multiplies the expected number of attempts by 32, so a 4 character prefix takes around a
+/// million hashes. Returns `None` if nothing was found, or if the prefix can never match because
+/// it is longer than a Publisher Id or contains characters outside the Crockford Base32 alphabet.
+#[cfg(feature = "alloc")]
+pub fn search_prefix(
+    base_publisher: &str,
+    desired_prefix: &str,
+    max_iters: u64,
+) -> Option<(alloc::string::String, PublisherId)> {
+    if desired_prefix.len() > PublisherId::LENGTH || !desired_prefix.bytes().all(is_crockford_byte)
+    {
+        return None;
+    }
+
+    (0..max_iters).find_map(|suffix| {
+        let publisher = alloc::format!("{base_publisher}{suffix}");
+        let publisher_id = PublisherId::new(&publisher);
+
+        publisher_id.as_str().as_bytes()[..desired_prefix.len()]
+            .eq_ignore_ascii_case(desired_prefix.as_bytes())
+            .then_some((publisher, publisher_id))
+    })
+}
+
+/// Returns whether `s` is a valid Publisher Id: exactly 13 characters of the Crockford Base32
+/// alphabet, in either case, with a last character whose padding bit is zero.
+///
+/// This is the same check as [`PublisherId::from_str`], without constructing the Publisher Id or
+/// an error, and can be used in `const` contexts.
+///
+/// ```
+/// use package_family_name::is_valid_publisher_id;
+///
+/// const VALID: bool = is_valid_publisher_id("8wekyb3d8bbwe");
+///
+/// assert!(VALID);
+/// assert!(!is_valid_publisher_id("8wekyb3d8bbwl"));
+/// ```
+pub const fn is_valid_publisher_id(s: &str) -> bool {
+    s.len() == PublisherId::LENGTH
+        && invalid_byte_index(s.as_bytes()).is_none()
+        && has_zero_padding_bit(s.as_bytes())
+}
+
+/// Returns the index of the first byte that isn't in the Crockford Base32 alphabet.
+const fn invalid_byte_index(bytes: &[u8]) -> Option<usize> {
+    let mut index = 0;
+    while index < bytes.len() {
+        if !is_crockford_byte(bytes[index]) {
+            return Some(index);
+        }
+        index += 1;
+    }
+    None
+}
+
+/// Returns whether the last of the Crockford Base32 `bytes` has an even value, so that the
+/// padding bit after the 64 hash bits is zero, as it is in every encoded hash.
+const fn has_zero_padding_bit(bytes: &[u8]) -> bool {
+    match bytes.last() {
+        Some(&byte) => matches!(crockford_value(byte), Some(value) if value % 2 == 0),
+        None => false,
+    }
+}
+
+/// Returns whether `byte` is in the Crockford Base32 alphabet, in either case.
+const fn is_crockford_byte(byte: u8) -> bool {
+    crockford_value(byte).is_some()
+}
+
+/// Returns the 5-bit value of a Crockford Base32 character, in either case.
+const fn crockford_value(byte: u8) -> Option<u8> {
+    let byte = byte.to_ascii_lowercase();
+    Some(match byte {
+        b'0'..=b'9' => byte - b'0',
+        b'a'..=b'h' => byte - b'a' + 10,
+        b'j' | b'k' => byte - b'j' + 18,
+        b'm' | b'n' => byte - b'm' + 20,
+        b'p'..=b't' => byte - b'p' + 22,
+        b'v'..=b'z' => byte - b'v' + 27,
+        _ => return None,
+    })
+}
+
+pub(crate) fn cmp_ignore_ascii_case(left: &str, right: &str) -> Ordering {
+    left.bytes()
+        .map(|byte| byte.to_ascii_lowercase())
+        .cmp(right.bytes().map(|byte| byte.to_ascii_lowercase()))
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use alloc::collections::BTreeSet;
+    use alloc::format;
+    use alloc::string::ToString;
+    use alloc::vec::Vec;
+    use core::cmp::Ordering;
+
+    use fast32::base32::CROCKFORD_LOWER;
+    use sha2::{Digest, Sha256, Sha512};
+
+    use crate::publisher_id::{decode_crockford, encode_crockford, encoded_len};
+    use crate::{
+        is_valid_publisher_id, publisher_digest, publisher_sha256, DigestTooShortError,
+        PublisherId, PublisherIdError,
+    };
+
+    #[test]
+    fn test_publisher_id() {
+        assert_eq!(
+            PublisherId::new(
+                "CN=Microsoft Corporation, O=Microsoft Corporation, L=Redmond, S=Washington, C=US"
+            )
+            .as_str(),
+            "8wekyb3d8bbwe"
+        );
+    }
+
+    #[test]
+    fn test_empty_publisher_id() {
+        assert_eq!(PublisherId::new(""), PublisherId::EMPTY_PUBLISHER_ID);
+        assert_eq!(PublisherId::EMPTY_PUBLISHER_ID, "werc8gmrzge18");
+        assert_eq!(
+            "werc8gmrzge18".parse::<PublisherId>(),
+            Ok(PublisherId::EMPTY_PUBLISHER_ID)
+        );
+    }
+
+    #[test]
+    fn test_new_with_digest() {
+        let (publisher_id, digest) = PublisherId::new_with_digest("Publisher Software");
+
+        assert_eq!(publisher_id, PublisherId::new("Publisher Software"));
+        assert_eq!(
+            publisher_id.as_str(),
+            CROCKFORD_LOWER.encode(&digest[..8]).as_str()
+        );
+    }
+
+    #[test]
+    fn test_new_with_digest_algo() {
+        assert_eq!(
+            PublisherId::new_with_digest_algo::<Sha256>("Publisher Software"),
+            PublisherId::new("Publisher Software").as_str()
+        );
+        assert_ne!(
+            PublisherId::new_with_digest_algo::<Sha512>("Publisher Software"),
+            PublisherId::new("Publisher Software").as_str()
+        );
+    }
+
+    #[test]
+    fn test_new_case_insensitive() {
+        let mixed_case = "CN=Hydraulic Software AG, L=Zürich";
+        let lowercase = "cn=hydraulic software ag, l=zürich";
+
+        assert_ne!(PublisherId::new(mixed_case), PublisherId::new(lowercase));
+        assert_eq!(
+            PublisherId::new_case_insensitive(mixed_case),
+            PublisherId::new_case_insensitive("CN=HYDRAULIC SOFTWARE AG, L=ZÜRICH")
+        );
+        assert_eq!(
+            PublisherId::new_case_insensitive(mixed_case),
+            PublisherId::new(lowercase)
+        );
+    }
+
+    #[test]
+    fn test_encoded_len() {
+        assert_eq!(encoded_len(8), PublisherId::LENGTH);
+
+        for byte_count in 1..=16 {
+            assert_eq!(
+                encoded_len(byte_count),
+                CROCKFORD_LOWER.encode(&[0xff; 16][..byte_count]).len()
+            );
+        }
+    }
+
+    #[test]
+    fn test_publisher_digest() {
+        let digest = publisher_digest("Publisher Software");
+
+        assert_eq!(digest.len(), 32);
+        assert_eq!(
+            PublisherId::from_digest(&digest[..8]).unwrap(),
+            PublisherId::new("Publisher Software")
+        );
+        assert_eq!(
+            <[u8; 32]>::from(digest),
+            PublisherId::new_with_digest("Publisher Software").1
+        );
+    }
+
+    #[test]
+    fn test_publisher_sha256() {
+        let digest = publisher_sha256("Publisher Software");
+
+        assert_eq!(
+            digest,
+            <[u8; 32]>::from(publisher_digest("Publisher Software"))
+        );
+        assert_eq!(
+            PublisherId::from_digest(&digest[..8]).unwrap(),
+            PublisherId::new("Publisher Software")
+        );
+        assert_eq!(
+            publisher_sha256(alloc::string::String::new()),
+            <[u8; 32]>::from(Sha256::digest([]))
+        );
+    }
+
+    #[test]
+    fn test_from_digest() {
+        let digest = Sha256::digest(
+            "Publisher Software"
+                .encode_utf16()
+                .flat_map(u16::to_le_bytes)
+                .collect::<Vec<_>>(),
+        );
+
+        assert_eq!(
+            PublisherId::from_digest(digest),
+            Ok(PublisherId::new("Publisher Software"))
+        );
+        assert_eq!(
+            PublisherId::from_digest([0; 7]),
+            Err(DigestTooShortError { len: 7 })
+        );
+    }
+
+    #[test]
+    fn test_encode_crockford_matches_fast32() {
+        let mut bytes = [0; 8];
+        for seed in 0..=u8::MAX {
+            for (index, byte) in bytes.iter_mut().enumerate() {
+                *byte = seed.wrapping_mul(31) ^ (index as u8).wrapping_mul(97);
+            }
+
+            assert_eq!(
+                encode_crockford(bytes),
+                CROCKFORD_LOWER.encode(&bytes).as_bytes()
+            );
+        }
+        assert_eq!(&encode_crockford([0xff; 8]), b"zzzzzzzzzzzzy");
+    }
+
+    #[test]
+    fn test_decode_crockford_matches_fast32() {
+        let mut bytes = [0; 8];
+        for seed in 0..=u8::MAX {
+            for (index, byte) in bytes.iter_mut().enumerate() {
+                *byte = seed.wrapping_mul(53) ^ (index as u8).wrapping_mul(29);
+            }
+            let encoded = encode_crockford(bytes);
+
+            assert_eq!(decode_crockford(&encoded), bytes);
+            assert_eq!(
+                decode_crockford(&encoded),
+                ((CROCKFORD_LOWER.decode_u128(&encoded).unwrap() >> 1) as u64).to_be_bytes()
+            );
+        }
+        assert_eq!(decode_crockford(b"ZZZZZZZZZZZZY"), [0xff; 8]);
+    }
+
+    #[test]
+    fn test_from_hash_bytes() {
+        let publisher_id = PublisherId::new("Publisher Software");
+        let hash_bytes = publisher_id.hash_bytes();
+
+        assert_eq!(PublisherId::from_hash_bytes(hash_bytes), publisher_id);
+        assert_eq!(
+            PublisherId::from_hash_bytes([0; 8]).as_str(),
+            "0000000000000"
+        );
+        assert_eq!(
+            PublisherId::from_hash_bytes([0xff; 8]).hash_bytes(),
+            [0xff; 8]
+        );
+    }
+
+    #[test]
+    fn test_from_utf16() {
+        let publisher = "CN=ÄÖÜ, O=日本 🦀".encode_utf16().collect::<Vec<_>>();
+
+        assert_eq!(
+            PublisherId::from_utf16(&publisher),
+            PublisherId::new("CN=ÄÖÜ, O=日本 🦀")
+        );
+        assert_eq!(PublisherId::from_utf16(&[]), PublisherId::new(""));
+
+        // An unpaired surrogate has no UTF-8 form, so only its SHA-256 digest can be checked
+        let unpaired_surrogate = [u16::from(b'C'), u16::from(b'N'), u16::from(b'='), 0xD800];
+        let digest = Sha256::digest([b'C', 0, b'N', 0, b'=', 0, 0x00, 0xD8]);
+        assert_eq!(
+            PublisherId::from_utf16(&unpaired_surrogate),
+            PublisherId::from_digest(digest).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_from_publishers() {
+        let publishers = ["CN=Contoso", "", "CN=Contoso", "CN=ÄÖÜ, O=日本"];
+
+        assert!(PublisherId::from_publishers(publishers)
+            .zip(publishers)
+            .all(|(publisher_id, publisher)| publisher_id == PublisherId::new(publisher)));
+        assert_eq!(PublisherId::from_publishers(Vec::<&str>::new()).count(), 0);
+    }
+
+    #[test]
+    fn test_from_hash_bytes_const() {
+        const PUBLISHER_ID: PublisherId = PublisherId::from_hash_bytes([0xff; 8]);
+
+        assert_eq!(PUBLISHER_ID.as_str(), "zzzzzzzzzzzzy");
+        assert_eq!(PUBLISHER_ID, PublisherId::from_hash_bytes([0xff; 8]));
+    }
+
+    #[test]
+    fn test_valid_next_chars() {
+        let valid_next_chars = PublisherId::valid_next_chars();
+
+        assert_eq!(valid_next_chars.len(), 32);
+        for omitted in ['i', 'l', 'o', 'u'] {
+            assert!(!valid_next_chars.contains(&omitted));
+        }
+        assert!(valid_next_chars
+            .iter()
+            .all(|&character| super::is_crockford_byte(character as u8)));
+
+        // Only the characters that leave the padding bit zero are accepted in the last position
+        for &last in valid_next_chars {
+            let publisher_id = format!("8wekyb3d8bbw{last}");
+            assert_eq!(
+                publisher_id.parse::<PublisherId>().is_ok(),
+                PublisherId::valid_last_chars().contains(&last),
+                "{publisher_id}"
+            );
+        }
+        assert_eq!(PublisherId::valid_last_chars().len(), 16);
+    }
+
+    #[test]
+    fn test_into_heapless() {
+        let publisher_id = PublisherId::new("Publisher Software");
+        let heapless = publisher_id.clone().into_heapless();
+
+        assert_eq!(heapless.len(), heapless.capacity());
+        assert_eq!(heapless.parse::<PublisherId>(), Ok(publisher_id));
+    }
+
+    #[test]
+    fn test_from_str_lowercases() {
+        let lowercase = "8wekyb3d8bbwe".parse::<PublisherId>().unwrap();
+        let uppercase = "8WEKYB3D8BBWE".parse::<PublisherId>().unwrap();
+
+        assert_eq!(lowercase, uppercase);
+        assert_eq!(uppercase.as_str(), "8wekyb3d8bbwe");
+        assert_eq!(format!("{uppercase}"), "8wekyb3d8bbwe");
+    }
+
+    #[test]
+    fn test_borrow_str() {
+        let set = ["8WEKYB3D8BBWE", "zj75k085cmj1a"]
+            .map(|publisher_id| publisher_id.parse::<PublisherId>().unwrap())
+            .into_iter()
+            .collect::<BTreeSet<_>>();
+
+        assert!(set.contains("8wekyb3d8bbwe"));
+        assert!(set.contains("zj75k085cmj1a"));
+        assert!(!set.contains("werc8gmrzge18"));
+    }
+
+    #[test]
+    fn test_search_prefix() {
+        let (publisher, publisher_id) = super::search_prefix("CN=Contoso ", "A", 10_000).unwrap();
+
+        assert!(publisher.starts_with("CN=Contoso "));
+        assert!(publisher_id.starts_with("a"));
+        assert_eq!(PublisherId::new(publisher), publisher_id);
+
+        assert_eq!(super::search_prefix("CN=Contoso ", "u", 10_000), None);
+    }
+
+    #[test]
+    fn test_partial_ord_str() {
+        let publisher_id = PublisherId::new(
+            "CN=Microsoft Corporation, O=Microsoft Corporation, L=Redmond, S=Washington, C=US",
+        );
+
+        assert!(publisher_id < "zzzzzzzzzzzzz");
+        assert!(publisher_id < "9");
+        assert!(publisher_id > "0000000000000");
+        assert!(publisher_id > "8WEKYB3D8BBW");
+        assert!(publisher_id <= "8WEKYB3D8BBWE");
+        assert!(publisher_id >= "8wekyb3d8bbwe");
+        assert_eq!(
+            publisher_id.partial_cmp("8WEKYB3D8BBWE"),
+            Some(Ordering::Equal)
+        );
+    }
+
+    #[test]
+    fn test_partial_eq_str() {
+        let publisher_id = PublisherId::new(
+            "CN=Microsoft Corporation, O=Microsoft Corporation, L=Redmond, S=Washington, C=US",
+        );
+
+        assert!(publisher_id == "8wekyb3d8bbwe");
+        assert!(publisher_id == "8WEKYB3D8BBWE");
+        assert!(publisher_id == *"8WekYB3d8bBwE");
+        assert!(publisher_id != "8wekyb3d8bbwc");
+        assert!(publisher_id != "8wekyb3d8bbw");
+        assert!(publisher_id != "");
+    }
+
+    #[test]
+    fn test_hex() {
+        // The first 8 bytes of the SHA-256 of the UTF-16LE empty string
+        let publisher_id = PublisherId::new("");
+
+        assert_eq!(format!("{publisher_id:x}"), "e3b0c44298fc1c14");
+        assert_eq!(format!("{publisher_id:X}"), "E3B0C44298FC1C14");
+        assert_eq!(format!("{publisher_id:#x}"), "0xe3b0c44298fc1c14");
+
+        let uppercase = "WERC8GMRZGE18".parse::<PublisherId>().unwrap();
+        assert_eq!(format!("{uppercase:x}"), "e3b0c44298fc1c14");
+
+        // Neighbouring last characters differ in the lowest hash bit, so their hex differs too
+        let even = "8wekyb3d8bbwe".parse::<PublisherId>().unwrap();
+        let next = "8wekyb3d8bbwg".parse::<PublisherId>().unwrap();
+        assert_eq!(format!("{even:x}"), "471d3f2c6d42d7c7");
+        assert_ne!(format!("{even:x}"), format!("{next:x}"));
+    }
+
+    #[test]
+    fn test_str_methods() {
+        let publisher_id = "8wekyb3d8bbwe".parse::<PublisherId>().unwrap();
+
+        assert!(publisher_id.starts_with("8wek"));
+        assert!(!publisher_id.starts_with("8WEK"));
+        assert!(publisher_id.ends_with("bbwe"));
+        assert!(!publisher_id.ends_with("BBWE"));
+        assert!(publisher_id.contains("b3d8"));
+        assert!(!publisher_id.contains("B3D8"));
+        assert!(publisher_id.chars().eq("8wekyb3d8bbwe".chars()));
+    }
+
+    #[test]
+    fn test_publisher_id_from_str() {
+        assert_eq!(
+            "8WEKYB3D8BBWE".parse::<PublisherId>(),
+            Ok(PublisherId::new(
+                "CN=Microsoft Corporation, O=Microsoft Corporation, L=Redmond, S=Washington, C=US"
+            ))
+        );
+        assert_eq!(
+            "8wekyb3d8bbw".parse::<PublisherId>(),
+            Err(PublisherIdError::InvalidLength { actual: 12 })
+        );
+        assert_eq!(
+            "8wekyb3d8bbwl".parse::<PublisherId>(),
+            Err(PublisherIdError::InvalidCharacters {
+                character: 'l',
+                index: 12
+            })
+        );
+    }
+
+    #[test]
+    fn test_invalid_last_character() {
+        for invalid in [
+            "8wekyb3d8bbwf",
+            "8WEKYB3D8BBWF",
+            "zzzzzzzzzzzzz",
+            "0000000000001",
+        ] {
+            assert!(!is_valid_publisher_id(invalid));
+            assert!(matches!(
+                invalid.parse::<PublisherId>(),
+                Err(PublisherIdError::InvalidLastCharacter { .. })
+            ));
+        }
+        assert_eq!(
+            "8WEKYB3D8BBWF".parse::<PublisherId>(),
+            Err(PublisherIdError::InvalidLastCharacter { character: 'F' })
+        );
+
+        // Every character that parses as the last one round-trips through the hash bytes
+        for last in PublisherId::valid_last_chars() {
+            let publisher_id = format!("8wekyb3d8bbw{last}")
+                .parse::<PublisherId>()
+                .unwrap();
+            assert_eq!(
+                PublisherId::from_hash_bytes(publisher_id.hash_bytes()),
+                publisher_id
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid_characters_reports_first() {
+        let error = "8wOkyb3d8bbwl".parse::<PublisherId>().unwrap_err();
+
+        assert_eq!(
+            error,
+            PublisherIdError::InvalidCharacters {
+                character: 'O',
+                index: 2
+            }
+        );
+        assert!(error.to_string().ends_with("but found 'O' at index 2"));
+        assert_eq!(
+            "8wekyb3d8bbé".parse::<PublisherId>(),
+            Err(PublisherIdError::InvalidCharacters {
+                character: 'é',
+                index: 11
+            })
+        );
+    }
+
+    #[test]
+    fn test_is_valid_publisher_id() {
+        for valid in ["8wekyb3d8bbwe", "8WEKYB3D8BBWE", "0000000000000"] {
+            assert!(is_valid_publisher_id(valid));
+            assert!(valid.parse::<PublisherId>().is_ok());
+        }
+        for invalid in [
+            "",
+            "8wekyb3d8bbw",
+            "8wekyb3d8bbwel",
+            "8wekyb3d8bbwl",
+            "8wekyb3d8bbé",
+        ] {
+            assert!(!is_valid_publisher_id(invalid));
+            assert!(invalid.parse::<PublisherId>().is_err());
+        }
+    }
+
+    #[test]
+    fn test_invalid_length_message() {
+        assert_eq!(
+            "8wekyb3d8bbw"
+                .parse::<PublisherId>()
+                .unwrap_err()
+                .to_string(),
+            "Publisher Id length was 12, expected 13"
+        );
+    }
+
+    #[test]
+    fn test_invalid_length_takes_priority() {
+        assert_eq!(
+            "8wekyb3d8bbwel".parse::<PublisherId>(),
+            Err(PublisherIdError::InvalidLength { actual: 14 })
+        );
+        assert_eq!(
+            "l".parse::<PublisherId>(),
+            Err(PublisherIdError::InvalidLength { actual: 1 })
+        );
+    }
+}
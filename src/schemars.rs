@@ -0,0 +1,75 @@
+use alloc::borrow::Cow;
+
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+
+use crate::{PackageFamilyName, PublisherId};
+
+/// Publisher Ids in their canonical lowercase form, ending with a character whose padding bit is
+/// zero.
+const PUBLISHER_ID_PATTERN: &str = "^[0-9a-hjkmnp-tv-z]{12}[02468acegjmprtwy]$";
+
+impl JsonSchema for PublisherId {
+    fn schema_name() -> Cow<'static, str> {
+        "PublisherId".into()
+    }
+
+    fn json_schema(_generator: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "description": "The 13-character Crockford Base32 hash of an MSIX identity publisher",
+            "minLength": Self::LENGTH,
+            "maxLength": Self::LENGTH,
+            "pattern": PUBLISHER_ID_PATTERN,
+        })
+    }
+}
+
+impl JsonSchema for PackageFamilyName<'_> {
+    fn schema_name() -> Cow<'static, str> {
+        "PackageFamilyName".into()
+    }
+
+    fn json_schema(_generator: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "description": "An MSIX Package Family Name of the form <name>_<publisherId>, such as \
+                Microsoft.WindowsTerminal_8wekyb3d8bbwe",
+            "pattern": "^.+_[0-9a-hjkmnp-tv-z]{12}[02468acegjmprtwy]$",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use schemars::schema_for;
+
+    use crate::{PackageFamilyName, PublisherId};
+
+    #[test]
+    fn test_publisher_id_schema() {
+        let schema = schema_for!(PublisherId);
+
+        assert_eq!(
+            schema.get("pattern").and_then(|pattern| pattern.as_str()),
+            Some("^[0-9a-hjkmnp-tv-z]{12}[02468acegjmprtwy]$")
+        );
+        assert_eq!(
+            schema.get("maxLength").and_then(|len| len.as_u64()),
+            Some(13)
+        );
+    }
+
+    #[test]
+    fn test_package_family_name_schema() {
+        let schema = schema_for!(PackageFamilyName);
+
+        assert_eq!(
+            schema.get("type").and_then(|ty| ty.as_str()),
+            Some("string")
+        );
+        assert_eq!(
+            schema.get("pattern").and_then(|pattern| pattern.as_str()),
+            Some("^.+_[0-9a-hjkmnp-tv-z]{12}[02468acegjmprtwy]$")
+        );
+    }
+}
@@ -0,0 +1,213 @@
+//! [`serde`](::serde) support, enabled with the `serde` feature.
+
+use alloc::string::String;
+use core::fmt;
+
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{PackageFamilyName, PublisherId};
+
+/// Serializes as the family name string, such as `Microsoft.WindowsTerminal_8wekyb3d8bbwe`.
+impl Serialize for PackageFamilyName<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// Deserializes from a family name string, which doesn't need to be borrowed from the input.
+impl<'de> Deserialize<'de> for PackageFamilyName<'_> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(PackageFamilyNameVisitor)
+    }
+}
+
+struct PackageFamilyNameVisitor;
+
+impl<'de> Visitor<'de> for PackageFamilyNameVisitor {
+    type Value = PackageFamilyName<'static>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a Package Family Name")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        v.parse().map_err(E::custom)
+    }
+}
+
+/// Serializes as the 13-character string in human-readable formats such as JSON, and as the 8
+/// bytes of the hash it was encoded from in compact binary formats.
+///
+/// Both forms round-trip to an equal Publisher Id, as [`hash_bytes`](PublisherId::hash_bytes)
+/// describes.
+impl Serialize for PublisherId {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(self.as_str())
+        } else {
+            serializer.serialize_bytes(&self.hash_bytes())
+        }
+    }
+}
+
+/// Deserializes from the same form that [`Serialize`] produces for the format.
+impl<'de> Deserialize<'de> for PublisherId {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(PublisherIdVisitor)
+        } else {
+            deserializer.deserialize_bytes(PublisherIdVisitor)
+        }
+    }
+}
+
+struct PublisherIdVisitor;
+
+impl<'de> Visitor<'de> for PublisherIdVisitor {
+    type Value = PublisherId;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a 13-character Publisher Id or the 8 bytes of its hash")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        v.parse().map_err(E::custom)
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        v.try_into()
+            .map(PublisherId::from_hash_bytes)
+            .map_err(|_| E::invalid_length(v.len(), &self))
+    }
+}
+
+/// Serializes a [`PackageFamilyName`] as a struct of its package name and Publisher Id rather than
+/// as one string, for use with `#[serde(with = "package_family_name::serde::as_struct")]`.
+///
+/// In JSON this looks like `{"name": "Microsoft.PowerShell", "publisher_id": "8wekyb3d8bbwe"}`,
+/// which is easier to edit by hand. The package name keeps its case exactly, and the Publisher Id
+/// is always in lowercase.
+pub mod as_struct {
+    use super::*;
+
+    #[derive(Serialize)]
+    struct Parts<'a> {
+        name: &'a str,
+        publisher_id: &'a PublisherId,
+    }
+
+    #[derive(Deserialize)]
+    struct OwnedParts {
+        name: String,
+        publisher_id: PublisherId,
+    }
+
+    pub fn serialize<S: Serializer>(
+        package_family_name: &PackageFamilyName<'_>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let (name, publisher_id) = package_family_name.as_parts();
+
+        Parts { name, publisher_id }.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<PackageFamilyName<'static>, D::Error> {
+        let OwnedParts { name, publisher_id } = OwnedParts::deserialize(deserializer)?;
+
+        Ok(PackageFamilyName::from_owned_parts(name, publisher_id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{PackageFamilyName, PublisherId};
+
+    #[test]
+    fn test_package_family_name_json() {
+        let package_family_name: PackageFamilyName =
+            "Microsoft.WindowsTerminal_8wekyb3d8bbwe".parse().unwrap();
+
+        let json = serde_json::to_string(&package_family_name).unwrap();
+        assert_eq!(json, r#""Microsoft.WindowsTerminal_8wekyb3d8bbwe""#);
+        assert_eq!(
+            serde_json::from_str::<PackageFamilyName>(&json).unwrap(),
+            package_family_name
+        );
+        assert!(
+            serde_json::from_str::<PackageFamilyName>(r#""Microsoft.WindowsTerminal""#).is_err()
+        );
+    }
+
+    #[test]
+    fn test_as_struct() {
+        #[derive(serde::Serialize, serde::Deserialize)]
+        struct Config {
+            #[serde(with = "crate::serde::as_struct")]
+            package: PackageFamilyName<'static>,
+        }
+
+        let config = Config {
+            package: "microsoft.POWERSHELL_8WEKYB3D8BBWE".parse().unwrap(),
+        };
+
+        let json = serde_json::to_string(&config).unwrap();
+        assert_eq!(
+            json,
+            r#"{"package":{"name":"microsoft.POWERSHELL","publisher_id":"8wekyb3d8bbwe"}}"#
+        );
+
+        let Config { package } = serde_json::from_str(&json).unwrap();
+        assert_eq!(package.package_name(), "microsoft.POWERSHELL");
+        assert_eq!(package.publisher_id().as_str(), "8wekyb3d8bbwe");
+    }
+
+    #[test]
+    fn test_deserialize_owned_strings() {
+        // Escape sequences mean the string can't be borrowed from the input
+        let escaped = r#""Microsoft\u002eWindowsTerminal_8wekyb3d8bbwe""#;
+        assert_eq!(
+            serde_json::from_str::<PackageFamilyName>(escaped).unwrap(),
+            "Microsoft.WindowsTerminal_8wekyb3d8bbwe"
+        );
+        assert_eq!(
+            serde_json::from_str::<PublisherId>(r#""8wekyb3d8bbw\u0065""#).unwrap(),
+            "8wekyb3d8bbwe"
+        );
+
+        // Readers can never lend out borrowed strings
+        let reader = r#"["Microsoft.WindowsTerminal_8wekyb3d8bbwe", "8wekyb3d8bbwe"]"#.as_bytes();
+        let (package_family_name, publisher_id): (PackageFamilyName<'static>, PublisherId) =
+            serde_json::from_reader(reader).unwrap();
+        assert_eq!(package_family_name.publisher_id(), &publisher_id);
+    }
+
+    #[test]
+    fn test_publisher_id_json() {
+        let publisher_id = "8wekyb3d8bbwe".parse::<PublisherId>().unwrap();
+
+        let json = serde_json::to_string(&publisher_id).unwrap();
+        assert_eq!(json, r#""8wekyb3d8bbwe""#);
+        assert_eq!(
+            serde_json::from_str::<PublisherId>(&json).unwrap(),
+            publisher_id
+        );
+        assert!(serde_json::from_str::<PublisherId>(r#""8wekyb3d8bbwl""#).is_err());
+    }
+
+    #[test]
+    fn test_publisher_id_binary() {
+        let publisher_id = "8wekyb3d8bbwe".parse::<PublisherId>().unwrap();
+
+        let bytes = postcard::to_allocvec(&publisher_id).unwrap();
+        // A length prefix followed by the 8 bytes of the hash
+        assert_eq!(bytes.len(), 9);
+        assert_eq!(&bytes[1..], publisher_id.hash_bytes());
+
+        let decoded = postcard::from_bytes::<PublisherId>(&bytes).unwrap();
+        assert_eq!(decoded, publisher_id);
+        assert!(postcard::from_bytes::<PublisherId>(&bytes[..8]).is_err());
+    }
+}
@@ -0,0 +1,48 @@
+//! [`proptest`] strategies, enabled with the `proptest` feature.
+
+use proptest::prelude::*;
+
+use crate::{validate_package_name, PackageFamilyName, PublisherId};
+
+/// Generates valid Publisher Ids from arbitrary hash bytes.
+pub fn publisher_id() -> impl Strategy<Value = PublisherId> {
+    any::<[u8; 8]>().prop_map(PublisherId::from_hash_bytes)
+}
+
+/// Generates family names with a valid MSIX package name and an arbitrary Publisher Id.
+pub fn package_family_name() -> impl Strategy<Value = PackageFamilyName<'static>> {
+    let package_name = "[A-Za-z0-9][A-Za-z0-9.-]{1,48}[A-Za-z0-9]"
+        .prop_filter("reserved package name", |name| {
+            validate_package_name(name).is_ok()
+        });
+
+    (package_name, publisher_id()).prop_map(|(package_name, publisher_id)| {
+        PackageFamilyName::from_owned_parts(package_name, publisher_id)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use proptest::prelude::*;
+
+    use crate::strategy::{package_family_name, publisher_id};
+    use crate::{validate_package_name, PackageFamilyName, PublisherId};
+
+    proptest! {
+        #[test]
+        fn test_publisher_id_round_trip(publisher_id in publisher_id()) {
+            prop_assert_eq!(publisher_id.to_string().parse::<PublisherId>(), Ok(publisher_id));
+        }
+
+        #[test]
+        fn test_package_family_name_round_trip(package_family_name in package_family_name()) {
+            prop_assert_eq!(validate_package_name(package_family_name.package_name()), Ok(()));
+            prop_assert_eq!(
+                package_family_name.to_string().parse::<PackageFamilyName>(),
+                Ok(package_family_name)
+            );
+        }
+    }
+}
@@ -0,0 +1,85 @@
+//! JavaScript bindings for calculating and parsing family names in the browser.
+
+use alloc::string::{String, ToString};
+
+use js_sys::{Object, Reflect};
+use wasm_bindgen::prelude::*;
+
+use crate::{PackageFamilyName, PackageFamilyNameError, PublisherId};
+
+/// Calculates the Publisher Id of an identity publisher.
+#[wasm_bindgen(js_name = publisherId)]
+pub fn publisher_id(publisher: &str) -> String {
+    PublisherId::new(publisher).to_string()
+}
+
+/// Calculates the Package Family Name from an identity name and an identity publisher.
+#[wasm_bindgen(js_name = packageFamilyName)]
+pub fn package_family_name(name: &str, publisher: &str) -> String {
+    PackageFamilyName::new(name, publisher).to_string()
+}
+
+/// The package name and Publisher Id of a parsed family name.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct ParsedFamilyName {
+    name: String,
+    publisher_id: String,
+}
+
+/// Parses a Package Family Name into a plain `{ name, publisherId }` object, throwing an `Error`
+/// with the reason if it is invalid.
+///
+/// The object is an ordinary JavaScript object, so it doesn't need to be freed and works with
+/// `JSON.stringify`.
+#[wasm_bindgen(js_name = parsePackageFamilyName)]
+pub fn parse_package_family_name(family_name: &str) -> Result<JsValue, JsError> {
+    let ParsedFamilyName { name, publisher_id } =
+        parse(family_name).map_err(|error| JsError::new(&error.to_string()))?;
+
+    let object = Object::new();
+    Reflect::set(&object, &"name".into(), &name.into()).unwrap_throw();
+    Reflect::set(&object, &"publisherId".into(), &publisher_id.into()).unwrap_throw();
+    Ok(object.into())
+}
+
+fn parse(family_name: &str) -> Result<ParsedFamilyName, PackageFamilyNameError> {
+    let family_name = PackageFamilyName::parse(family_name)?;
+
+    Ok(ParsedFamilyName {
+        name: family_name.package_name().to_string(),
+        publisher_id: family_name.publisher_id().to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::wasm::{package_family_name, parse, publisher_id};
+    use crate::{PackageFamilyNameError, PublisherIdError};
+
+    #[test]
+    fn test_publisher_id() {
+        assert_eq!(publisher_id("Publisher Software"), "zj75k085cmj1a");
+    }
+
+    #[test]
+    fn test_package_family_name() {
+        assert_eq!(
+            package_family_name("AppName", "Publisher Software"),
+            "AppName_zj75k085cmj1a"
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        let parsed = parse("Microsoft.WindowsTerminal_8wekyb3d8bbwe").unwrap();
+
+        assert_eq!(parsed.name, "Microsoft.WindowsTerminal");
+        assert_eq!(parsed.publisher_id, "8wekyb3d8bbwe");
+        assert_eq!(
+            parse("Microsoft.WindowsTerminal_8wekyb3d8"),
+            Err(PackageFamilyNameError::PublisherId(
+                PublisherIdError::InvalidLength { actual: 9 }
+            ))
+        );
+    }
+}
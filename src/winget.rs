@@ -0,0 +1,128 @@
+//! Checks for [winget-pkgs](https://github.com/microsoft/winget-pkgs) manifests.
+
+use alloc::string::{String, ToString};
+
+use thiserror::Error;
+
+use crate::package_family_name::eq_ignore_case;
+use crate::PackageFamilyName;
+
+/// The `PackageFamilyName` declared in a winget manifest doesn't match the installer.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error(
+    "PackageFamilyName is declared as {declared} but the installer's is {computed} ({})",
+    self.reason()
+)]
+pub struct WingetMismatch {
+    /// The family name declared in the manifest.
+    pub declared: String,
+    /// The family name calculated from the installer's identity.
+    pub computed: String,
+    /// Whether the package names match, ignoring case.
+    pub name_matches: bool,
+    /// Whether the Publisher Ids match, meaning the publisher matches the installer's signing
+    /// certificate.
+    pub publisher_id_matches: bool,
+}
+
+impl WingetMismatch {
+    const fn reason(&self) -> &'static str {
+        match (self.name_matches, self.publisher_id_matches) {
+            (true, false) => {
+                "the Publisher Id differs, so the publisher doesn't match the installer's signing certificate"
+            }
+            (false, false) => {
+                "the package name and the Publisher Id differ, so the publisher doesn't match the installer's signing certificate"
+            }
+            (_, true) => "the package name differs",
+        }
+    }
+}
+
+/// Checks that the `PackageFamilyName` declared in a winget manifest matches the one calculated
+/// from the MSIX installer's identity name and publisher.
+///
+/// winget-pkgs requires these to be equal. Like Windows, the comparison ignores case.
+pub fn check_manifest_family_name(
+    declared: &PackageFamilyName<'_>,
+    name: &str,
+    publisher_dn: &str,
+) -> Result<(), WingetMismatch> {
+    let computed = PackageFamilyName::new(name, publisher_dn);
+
+    if *declared == computed {
+        return Ok(());
+    }
+
+    Err(WingetMismatch {
+        declared: declared.to_string(),
+        computed: computed.to_string(),
+        name_matches: eq_ignore_case(declared.package_name(), name),
+        publisher_id_matches: declared.publisher_id() == computed.publisher_id(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use crate::winget::{check_manifest_family_name, WingetMismatch};
+
+    const MICROSOFT: &str =
+        "CN=Microsoft Corporation, O=Microsoft Corporation, L=Redmond, S=Washington, C=US";
+
+    #[test]
+    fn test_matching_family_name() {
+        let declared = "microsoft.windowsterminal_8wekyb3d8bbwe".parse().unwrap();
+
+        assert_eq!(
+            check_manifest_family_name(&declared, "Microsoft.WindowsTerminal", MICROSOFT),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_mismatching_family_name() {
+        let declared = "Microsoft.WindowsTerminal_8wekyb3d8bbwe".parse().unwrap();
+
+        let mismatch =
+            check_manifest_family_name(&declared, "Microsoft.WindowsTerminal", "CN=Contoso")
+                .unwrap_err();
+        assert!(mismatch.name_matches);
+        assert!(!mismatch.publisher_id_matches);
+        assert_eq!(
+            mismatch.to_string(),
+            "PackageFamilyName is declared as Microsoft.WindowsTerminal_8wekyb3d8bbwe but the \
+            installer's is Microsoft.WindowsTerminal_h91ms92gdsmmt (the Publisher Id differs, so \
+            the publisher doesn't match the installer's signing certificate)"
+        );
+
+        assert_eq!(
+            check_manifest_family_name(&declared, "Microsoft.WindowsTerminalPreview", MICROSOFT),
+            Err(WingetMismatch {
+                declared: "Microsoft.WindowsTerminal_8wekyb3d8bbwe".to_string(),
+                computed: "Microsoft.WindowsTerminalPreview_8wekyb3d8bbwe".to_string(),
+                name_matches: false,
+                publisher_id_matches: true,
+            })
+        );
+    }
+
+    #[test]
+    fn test_mismatching_name_and_publisher_id() {
+        let declared = "Microsoft.WindowsTerminal_8wekyb3d8bbwe".parse().unwrap();
+
+        let mismatch =
+            check_manifest_family_name(&declared, "Microsoft.WindowsTerminalPreview", "CN=Contoso")
+                .unwrap_err();
+        assert!(!mismatch.name_matches);
+        assert!(!mismatch.publisher_id_matches);
+        assert_eq!(
+            mismatch.to_string(),
+            "PackageFamilyName is declared as Microsoft.WindowsTerminal_8wekyb3d8bbwe but the \
+            installer's is Microsoft.WindowsTerminalPreview_h91ms92gdsmmt (the package name and \
+            the Publisher Id differ, so the publisher doesn't match the installer's signing \
+            certificate)"
+        );
+    }
+}
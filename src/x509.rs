@@ -0,0 +1,262 @@
+use alloc::string::String;
+use core::fmt::Write;
+
+use thiserror::Error;
+use x509_cert::attr::AttributeTypeAndValue;
+use x509_cert::der::asn1::ObjectIdentifier;
+use x509_cert::der::{self, Encode, Tag, Tagged};
+use x509_cert::name::Name;
+use x509_cert::Certificate;
+
+use crate::publisher::push_value;
+use crate::PublisherId;
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum CertError {
+    #[error("PEM does not contain a certificate")]
+    NoCertificate,
+    #[error("Invalid certificate: {0}")]
+    Invalid(der::Error),
+    #[error("Certificate has an empty subject")]
+    EmptySubject,
+}
+
+impl PublisherId {
+    /// Calculates the Publisher Id from the subject of a signing certificate.
+    ///
+    /// The subject is formatted the way Windows displays it in an MSIX publisher: the most
+    /// specific RDN first, such as `CN=Contoso, O=Contoso, L=Redmond, S=Washington, C=US`, with
+    /// attributes that Windows has no key for shown as a dotted OID, such as `OID.2.5.4.15`.
+    pub fn from_certificate(certificate: &Certificate) -> Self {
+        Self::new(format_name(&certificate.tbs_certificate.subject))
+    }
+
+    /// Calculates the Publisher Id from the subject of a PEM-encoded signing certificate.
+    ///
+    /// If the PEM contains a certificate chain, the first (leaf) certificate is used. A
+    /// certificate without a subject, such as one that only names its subject in the subject
+    /// alternative name extension, can't sign an MSIX package and is an error.
+    pub fn from_pem(pem: &str) -> Result<Self, CertError> {
+        if !pem.contains("-----BEGIN CERTIFICATE-----") {
+            return Err(CertError::NoCertificate);
+        }
+
+        let certificate = Certificate::load_pem_chain(pem.as_bytes())
+            .map_err(CertError::Invalid)?
+            .into_iter()
+            .next()
+            .ok_or(CertError::NoCertificate)?;
+
+        if certificate.tbs_certificate.subject.0.is_empty() {
+            return Err(CertError::EmptySubject);
+        }
+
+        Ok(Self::from_certificate(&certificate))
+    }
+
+    /// Calculates the Publisher Id from the subject of a PEM-encoded signing certificate.
+    #[deprecated(note = "use from_pem")]
+    #[inline]
+    pub fn from_cert_pem(pem: &str) -> Result<Self, CertError> {
+        Self::from_pem(pem)
+    }
+}
+
+/// Formats a distinguished name the way Windows displays it in an MSIX publisher, with the most
+/// specific RDN first, such as `CN=Contoso, O=Contoso, L=Redmond, S=Washington, C=US`.
+fn format_name(name: &Name) -> String {
+    let mut dn = String::new();
+
+    for (index, rdn) in name.0.iter().rev().enumerate() {
+        if index > 0 {
+            dn.push_str(", ");
+        }
+
+        for (index, attribute) in rdn.0.iter().enumerate() {
+            if index > 0 {
+                dn.push_str(" + ");
+            }
+            write_attribute(&mut dn, attribute);
+        }
+    }
+
+    dn
+}
+
+fn write_attribute(dn: &mut String, attribute: &AttributeTypeAndValue) {
+    match attribute_key(attribute.oid) {
+        Some(key) => dn.push_str(key),
+        None => {
+            let _ = write!(dn, "OID.{}", attribute.oid);
+        }
+    }
+    dn.push('=');
+
+    let value = &attribute.value;
+    let text = match value.tag() {
+        Tag::Utf8String
+        | Tag::PrintableString
+        | Tag::TeletexString
+        | Tag::Ia5String
+        | Tag::VisibleString => core::str::from_utf8(value.value()).ok().map(String::from),
+        Tag::BmpString => char::decode_utf16(
+            value
+                .value()
+                .chunks_exact(2)
+                .map(|pair| u16::from_be_bytes([pair[0], pair[1]])),
+        )
+        .collect::<Result<String, _>>()
+        .ok(),
+        _ => None,
+    };
+
+    match text {
+        Some(text) => push_value(dn, &text),
+        None => {
+            // Values that aren't strings are shown as their hex-encoded DER
+            dn.push('#');
+            for byte in value.to_der().unwrap_or_default() {
+                let _ = write!(dn, "{byte:02X}");
+            }
+        }
+    }
+}
+
+/// Returns the key Windows uses for an attribute, or `None` if it is shown as a dotted OID.
+fn attribute_key(oid: ObjectIdentifier) -> Option<&'static str> {
+    Some(match oid.as_bytes() {
+        [0x55, 0x04, 0x03] => "CN",
+        [0x55, 0x04, 0x04] => "SN",
+        [0x55, 0x04, 0x05] => "SERIALNUMBER",
+        [0x55, 0x04, 0x06] => "C",
+        [0x55, 0x04, 0x07] => "L",
+        [0x55, 0x04, 0x08] => "S",
+        [0x55, 0x04, 0x09] => "STREET",
+        [0x55, 0x04, 0x0A] => "O",
+        [0x55, 0x04, 0x0B] => "OU",
+        [0x55, 0x04, 0x0C] => "T",
+        [0x55, 0x04, 0x11] => "PostalCode",
+        [0x55, 0x04, 0x2A] => "G",
+        [0x55, 0x04, 0x2B] => "I",
+        [0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D, 0x01, 0x09, 0x01] => "E",
+        [0x09, 0x92, 0x26, 0x89, 0x93, 0xF2, 0x2C, 0x64, 0x01, 0x19] => "DC",
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+
+    use x509_cert::der::DecodePem;
+    use x509_cert::Certificate;
+
+    use crate::x509::{format_name, CertError};
+    use crate::PublisherId;
+
+    const MICROSOFT: &str = include_str!("../tests/certificates/microsoft.pem");
+    /// A self-signed certificate with the subject of Hydraulic's Conveyor signing certificate.
+    const CONVEYOR: &str = include_str!("../tests/certificates/conveyor.pem");
+    const ROOT: &str = include_str!("../tests/certificates/root.pem");
+    /// A self-signed certificate with the subject fields of an extended validation certificate.
+    const EXTENDED_VALIDATION: &str = include_str!("../tests/certificates/extended_validation.pem");
+    const EMPTY_SUBJECT: &str = include_str!("../tests/certificates/empty_subject.pem");
+
+    #[test]
+    fn test_from_certificate() {
+        let certificate = Certificate::from_pem(MICROSOFT).unwrap();
+
+        assert_eq!(
+            format_name(&certificate.tbs_certificate.subject),
+            "CN=Microsoft Corporation, O=Microsoft Corporation, L=Redmond, S=Washington, C=US"
+        );
+        assert_eq!(
+            PublisherId::from_certificate(&certificate).as_str(),
+            "8wekyb3d8bbwe"
+        );
+    }
+
+    #[test]
+    fn test_from_certificate_non_ascii_subject() {
+        let certificate = Certificate::from_pem(CONVEYOR).unwrap();
+
+        assert_eq!(
+            format_name(&certificate.tbs_certificate.subject),
+            "CN=Hydraulic Software AG, O=Hydraulic Software AG, L=Zürich, S=Zürich, C=CH"
+        );
+        assert_eq!(
+            PublisherId::from_certificate(&certificate).as_str(),
+            "49jahnq5qzr1m"
+        );
+    }
+
+    #[test]
+    fn test_from_certificate_extended_validation() {
+        let certificate = Certificate::from_pem(EXTENDED_VALIDATION).unwrap();
+
+        assert_eq!(
+            format_name(&certificate.tbs_certificate.subject),
+            "CN=Contoso Ltd, O=Contoso Ltd, STREET=1 Main St, L=Redmond, S=Washington, C=US, \
+            OID.1.3.6.1.4.1.311.60.2.1.3=US, SERIALNUMBER=600413485, OID.2.5.4.15=Private Organization"
+        );
+        assert_eq!(
+            PublisherId::from_certificate(&certificate).as_str(),
+            "0wgvnbzg7vzny"
+        );
+    }
+
+    #[test]
+    fn test_from_pem() {
+        let publisher_id = PublisherId::from_pem(MICROSOFT).unwrap();
+
+        assert_eq!(publisher_id.as_str(), "8wekyb3d8bbwe");
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_from_cert_pem() {
+        assert_eq!(
+            PublisherId::from_cert_pem(MICROSOFT),
+            PublisherId::from_pem(MICROSOFT)
+        );
+        assert_eq!(
+            PublisherId::from_cert_pem("not a certificate"),
+            Err(CertError::NoCertificate)
+        );
+    }
+
+    #[test]
+    fn test_from_pem_chain_uses_leaf() {
+        let chain = String::from(MICROSOFT) + ROOT;
+
+        assert_eq!(
+            PublisherId::from_pem(&chain).unwrap().as_str(),
+            "8wekyb3d8bbwe"
+        );
+    }
+
+    #[test]
+    fn test_from_pem_without_certificate() {
+        assert_eq!(PublisherId::from_pem(""), Err(CertError::NoCertificate));
+        assert_eq!(
+            PublisherId::from_pem("-----BEGIN PUBLIC KEY-----\n-----END PUBLIC KEY-----\n"),
+            Err(CertError::NoCertificate)
+        );
+    }
+
+    #[test]
+    fn test_from_pem_empty_subject() {
+        assert_eq!(
+            PublisherId::from_pem(EMPTY_SUBJECT),
+            Err(CertError::EmptySubject)
+        );
+    }
+
+    #[test]
+    fn test_from_pem_malformed() {
+        assert!(matches!(
+            PublisherId::from_pem("-----BEGIN CERTIFICATE-----\nAAAA\n-----END CERTIFICATE-----\n"),
+            Err(CertError::Invalid(_))
+        ));
+    }
+}
@@ -0,0 +1,116 @@
+//! Family names checked against ones published for real MSIX packages, followed by publisher edge
+//! cases that no published package is known to cover.
+
+#![cfg(feature = "alloc")]
+
+use package_family_name::PackageFamilyName;
+
+const MICROSOFT: &str =
+    "CN=Microsoft Corporation, O=Microsoft Corporation, L=Redmond, S=Washington, C=US";
+
+const MICROSOFT_WINDOWS: &str =
+    "CN=Microsoft Windows, O=Microsoft Corporation, L=Redmond, S=Washington, C=US";
+
+/// `(identity name, identity publisher, family name)` of published packages.
+const PACKAGES: &[(&str, &str, &str)] = &[
+    (
+        "Microsoft.WindowsTerminal",
+        MICROSOFT,
+        "Microsoft.WindowsTerminal_8wekyb3d8bbwe",
+    ),
+    (
+        "Microsoft.DesktopAppInstaller",
+        MICROSOFT,
+        "Microsoft.DesktopAppInstaller_8wekyb3d8bbwe",
+    ),
+    (
+        "MicrosoftCorporationII.WindowsSubsystemForLinux",
+        MICROSOFT,
+        "MicrosoftCorporationII.WindowsSubsystemForLinux_8wekyb3d8bbwe",
+    ),
+    (
+        "Microsoft.AAD.BrokerPlugin",
+        MICROSOFT_WINDOWS,
+        "Microsoft.AAD.BrokerPlugin_cw5n1h2txyewy",
+    ),
+    (
+        "NotepadPlusPlus",
+        r#"CN="Notepad++", O="Notepad++", L=Saint Cloud, S=Ile-de-France, C=FR"#,
+        "NotepadPlusPlus_7njy0v32s6xk6",
+    ),
+    (
+        "PythonSoftwareFoundation.Python.3.12",
+        "CN=4975D53F-AA7E-49A5-8B49-EA4FDC1BB66B",
+        "PythonSoftwareFoundation.Python.3.12_qbz5n2kfra8p0",
+    ),
+    (
+        "SpotifyAB.SpotifyMusic",
+        "CN=453637B3-4E12-4CDF-B0D3-2A3C863BF6EF",
+        "SpotifyAB.SpotifyMusic_zpdnekdrzrea0",
+    ),
+    (
+        "CanonicalGroupLimited.Ubuntu",
+        "CN=23596F84-C3EA-4CD8-A7DF-550DCE37BCD0",
+        "CanonicalGroupLimited.Ubuntu_79rhkp1fndgsc",
+    ),
+    (
+        "Mozilla.Firefox",
+        "CN=082E9164-EE6C-4EC8-B62C-441FAE7BEFA1",
+        "Mozilla.Firefox_n80bbvh6b1yt2",
+    ),
+    (
+        "5319275A.WhatsAppDesktop",
+        "CN=24803D75-212C-471A-BC57-9EF86AB91435",
+        "5319275A.WhatsAppDesktop_cv1g1gvanyjgm",
+    ),
+    (
+        "Clipchamp.Clipchamp",
+        "CN=33F0F141-36F3-4EC2-A77D-51B53D0BA0E4",
+        "Clipchamp.Clipchamp_yxz26nhyzhsrt",
+    ),
+];
+
+/// `(identity name, identity publisher, family name)` with publishers shaped like extended
+/// validation and non-ASCII certificate subjects. The expected values were calculated separately
+/// with Python's `hashlib` rather than taken from published packages.
+const EDGE_CASES: &[(&str, &str, &str)] = &[
+    (
+        "Contoso.App",
+        "CN=Contoso Ltd, O=Contoso Ltd, STREET=1 Main St, L=Redmond, S=Washington, C=US, \
+        OID.1.3.6.1.4.1.311.60.2.1.3=US, SERIALNUMBER=600413485, OID.2.5.4.15=Private Organization",
+        "Contoso.App_0wgvnbzg7vzny",
+    ),
+    (
+        "Contoso.App",
+        "CN=Contoso Ltd, O=Contoso Ltd, L=Zürich, S=Zürich, C=CH",
+        "Contoso.App_ddtxfxb618sf4",
+    ),
+    (
+        "Contoso.App",
+        "CN=株式会社コントソ, O=株式会社コントソ, C=JP",
+        "Contoso.App_6a1b2qjr02zvw",
+    ),
+    ("Contoso.App", "CN=Contoso 😀", "Contoso.App_phxc596hrcacy"),
+];
+
+#[test]
+fn test_published_packages() {
+    for &(name, publisher, expected) in PACKAGES {
+        assert_eq!(
+            PackageFamilyName::new(name, publisher).to_string(),
+            expected,
+            "{publisher}"
+        );
+    }
+}
+
+#[test]
+fn test_publisher_edge_cases() {
+    for &(name, publisher, expected) in EDGE_CASES {
+        assert_eq!(
+            PackageFamilyName::new(name, publisher).to_string(),
+            expected,
+            "{publisher}"
+        );
+    }
+}